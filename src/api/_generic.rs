@@ -2,6 +2,7 @@ use crate::errors::{RabbitMqApiError, RabbitMqClientError};
 use http::StatusCode;
 use reqwest::Response;
 use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer};
 
 #[tracing::instrument(skip(response))]
 pub async fn handle_response<T>(response: Response) -> Result<T, RabbitMqClientError>
@@ -41,7 +42,7 @@ pub async fn handle_empty_response(response: Response) -> Result<(), RabbitMqCli
     }
 }
 
-fn map_error(status: StatusCode, text: String) -> RabbitMqClientError {
+pub fn map_error(status: StatusCode, text: String) -> RabbitMqClientError {
     if status.eq(&StatusCode::UNAUTHORIZED) {
         return RabbitMqClientError::Unauthorized;
     }
@@ -52,3 +53,20 @@ fn map_error(status: StatusCode, text: String) -> RabbitMqClientError {
 
     RabbitMqClientError::ApiError(RabbitMqApiError { code: status, text })
 }
+
+pub fn deserialize_uris<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Uris {
+        Single(String),
+        Multiple(Vec<String>),
+    }
+
+    Ok(match Uris::deserialize(deserializer)? {
+        Uris::Single(uri) => vec![uri],
+        Uris::Multiple(uris) => uris,
+    })
+}
@@ -0,0 +1,28 @@
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'!')
+    .remove(b'~')
+    .remove(b'*')
+    .remove(b'\'')
+    .remove(b'(')
+    .remove(b')');
+
+pub fn encode_path_segment(segment: &str) -> String {
+    utf8_percent_encode(segment, PATH_SEGMENT).to_string()
+}
+
+macro_rules! api_url {
+    ($client:expr, $path:literal $(, $segment:expr)* $(,)?) => {
+        format!(
+            concat!("{}", $path),
+            $client.api_url,
+            $($crate::api::_url::encode_path_segment(&$segment.to_string())),*
+        )
+    };
+}
+
+pub(crate) use api_url;
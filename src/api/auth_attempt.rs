@@ -0,0 +1,76 @@
+use crate::api::_generic::handle_response;
+use crate::api::_url::api_url;
+use crate::errors::RabbitMqClientError;
+use crate::RabbitMqClient;
+use async_trait::async_trait;
+use serde::Deserialize;
+
+#[async_trait]
+pub trait AuthAttemptApi {
+    async fn get_auth_attempts(
+        &self,
+        node: String,
+    ) -> Result<Vec<RabbitMqAuthAttempts>, RabbitMqClientError>;
+
+    async fn get_auth_attempts_by_source(
+        &self,
+        node: String,
+    ) -> Result<Vec<RabbitMqAuthAttemptsBySource>, RabbitMqClientError>;
+}
+
+#[async_trait]
+impl AuthAttemptApi for RabbitMqClient {
+    #[tracing::instrument(skip(self))]
+    async fn get_auth_attempts(
+        &self,
+        node: String,
+    ) -> Result<Vec<RabbitMqAuthAttempts>, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/auth/attempts/{}", node),
+            )
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_auth_attempts_by_source(
+        &self,
+        node: String,
+    ) -> Result<Vec<RabbitMqAuthAttemptsBySource>, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/auth/attempts/{}/source", node),
+            )
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqAuthAttempts {
+    pub protocol: String,
+    pub auth_attempts: u64,
+    pub auth_attempts_failed: u64,
+    pub auth_attempts_succeeded: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqAuthAttemptsBySource {
+    pub protocol: String,
+    #[serde(default)]
+    pub remote_address: String,
+    #[serde(default)]
+    pub username: String,
+    pub auth_attempts: u64,
+    pub auth_attempts_failed: u64,
+    pub auth_attempts_succeeded: u64,
+}
@@ -1,4 +1,6 @@
 use crate::api::_generic::{handle_empty_response, handle_response};
+use crate::api::_url::api_url;
+use crate::api::queue::RabbitMqArgument;
 use crate::errors::RabbitMqClientError;
 use crate::RabbitMqClient;
 use async_trait::async_trait;
@@ -58,11 +60,7 @@ impl BindingApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::GET,
-                format!(
-                    "{}/api/bindings/{}",
-                    self.api_url,
-                    vhost.unwrap_or_default()
-                ),
+                api_url!(self, "/api/bindings/{}", vhost.unwrap_or_default()),
             )
             .send()
             .await?;
@@ -86,9 +84,13 @@ impl BindingApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::GET,
-                format!(
-                    "{}/api/bindings/{}/e/{}/{}/{}",
-                    self.api_url, vhost, source, destination_type, destination
+                api_url!(
+                    self,
+                    "/api/bindings/{}/e/{}/{}/{}",
+                    vhost,
+                    source,
+                    destination_type,
+                    destination
                 ),
             )
             .send()
@@ -114,9 +116,14 @@ impl BindingApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::GET,
-                format!(
-                    "{}/api/bindings/{}/e/{}/{}/{}/{}",
-                    self.api_url, vhost, source, destination_type, destination, properties_key
+                api_url!(
+                    self,
+                    "/api/bindings/{}/e/{}/{}/{}/{}",
+                    vhost,
+                    source,
+                    destination_type,
+                    destination,
+                    properties_key
                 ),
             )
             .send()
@@ -142,9 +149,13 @@ impl BindingApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::POST,
-                format!(
-                    "{}/api/bindings/{}/e/{}/{}/{}",
-                    self.api_url, vhost, source, destination_type, destination
+                api_url!(
+                    self,
+                    "/api/bindings/{}/e/{}/{}/{}",
+                    vhost,
+                    source,
+                    destination_type,
+                    destination
                 ),
             )
             .json(&request)
@@ -190,9 +201,14 @@ impl BindingApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::DELETE,
-                format!(
-                    "{}/api/bindings/{}/e/{}/{}/{}/{}",
-                    self.api_url, vhost, source, destination_type, destination, properties_key
+                api_url!(
+                    self,
+                    "/api/bindings/{}/e/{}/{}/{}/{}",
+                    vhost,
+                    source,
+                    destination_type,
+                    destination,
+                    properties_key
                 ),
             )
             .send()
@@ -210,10 +226,11 @@ pub struct RabbitMqBinding {
     pub destination_type: RabbitMqBindingDestinationType,
     pub routing_key: String,
     pub properties_key: String,
-    pub arguments: HashMap<String, String>,
+    #[serde(default)]
+    pub arguments: HashMap<String, RabbitMqArgument>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub enum RabbitMqBindingDestinationType {
     #[serde(rename = "exchange")]
     Exchange,
@@ -221,10 +238,10 @@ pub enum RabbitMqBindingDestinationType {
     Queue,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Default, Serialize)]
 pub struct RabbitMqBindingRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub routing_key: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub arguments: Option<HashMap<String, String>>,
+    pub arguments: Option<HashMap<String, RabbitMqArgument>>,
 }
@@ -0,0 +1,176 @@
+use crate::api::_generic::handle_response;
+use crate::api::_url::api_url;
+use crate::api::options::pagination::RabbitMqPaginationRequest;
+use crate::api::queue::RabbitMqArgument;
+use crate::api::RabbitMqPaginatedResponse;
+use crate::errors::RabbitMqClientError;
+use crate::RabbitMqClient;
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use super::options::RabbitMqRequestOptions;
+
+#[async_trait]
+pub trait ChannelApi {
+    async fn list_channels(
+        &self,
+        vhost: Option<String>,
+        options: Option<RabbitMqRequestOptions>,
+    ) -> Result<RabbitMqPaginatedResponse<RabbitMqChannel>, RabbitMqClientError>;
+
+    async fn list_connection_channels(
+        &self,
+        connection: String,
+    ) -> Result<Vec<RabbitMqChannel>, RabbitMqClientError>;
+
+    async fn get_channel(&self, channel: String) -> Result<RabbitMqChannel, RabbitMqClientError>;
+}
+
+#[async_trait]
+impl ChannelApi for RabbitMqClient {
+    #[tracing::instrument(skip(self))]
+    async fn list_channels(
+        &self,
+        vhost: Option<String>,
+        options: Option<RabbitMqRequestOptions>,
+    ) -> Result<RabbitMqPaginatedResponse<RabbitMqChannel>, RabbitMqClientError> {
+        let options: RabbitMqRequestOptions = options.unwrap_or_default();
+        let pagination: RabbitMqPaginationRequest = options.pagination.unwrap_or_default().into();
+
+        let url = match vhost {
+            None => api_url!(self, "/api/channels"),
+            Some(vhost) => api_url!(self, "/api/vhosts/{}/channels", vhost),
+        };
+
+        let response = self
+            .client
+            .request(reqwest::Method::GET, url)
+            .query(&pagination)
+            .query(&options.sorting)
+            .query(&[("disable_stats", options.disable_stats)])
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_connection_channels(
+        &self,
+        connection: String,
+    ) -> Result<Vec<RabbitMqChannel>, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/connections/{}/channels", connection),
+            )
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_channel(&self, channel: String) -> Result<RabbitMqChannel, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/channels/{}", channel),
+            )
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqChannel {
+    pub name: String,
+    pub node: String,
+    pub number: i64,
+    pub vhost: String,
+    pub user: String,
+    pub state: Option<RabbitMqChannelState>,
+    pub connection_details: Option<RabbitMqChannelConnectionDetails>,
+    pub prefetch_count: Option<i64>,
+    pub global_prefetch_count: Option<i64>,
+    pub consumer_count: Option<i64>,
+    pub messages_unacknowledged: Option<i64>,
+    pub messages_unconfirmed: Option<i64>,
+    pub messages_uncommitted: Option<i64>,
+    pub acks_uncommitted: Option<i64>,
+    pub pending_raft_commands: Option<i64>,
+    #[serde(default)]
+    pub confirm: bool,
+    #[serde(default)]
+    pub transactional: bool,
+    pub idle_since: Option<String>,
+    #[serde(default)]
+    pub consumer_details: Vec<RabbitMqChannelConsumer>,
+    pub message_stats: Option<RabbitMqChannelMessageStats>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RabbitMqChannelState {
+    Starting,
+    Running,
+    Flow,
+    Closing,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqChannelConnectionDetails {
+    pub name: String,
+    pub peer_host: Option<String>,
+    pub peer_port: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqChannelConsumer {
+    pub consumer_tag: String,
+    pub queue: RabbitMqQueueReference,
+    pub ack_required: bool,
+    pub prefetch_count: i64,
+    pub exclusive: bool,
+    #[serde(default)]
+    pub arguments: HashMap<String, RabbitMqArgument>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqQueueReference {
+    pub name: String,
+    pub vhost: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqChannelMessageStats {
+    #[serde(default)]
+    pub ack: i64,
+    #[serde(default)]
+    pub confirm: i64,
+    #[serde(default)]
+    pub deliver: i64,
+    #[serde(default)]
+    pub deliver_get: i64,
+    #[serde(default)]
+    pub deliver_no_ack: i64,
+    #[serde(default)]
+    pub drop_unroutable: i64,
+    #[serde(default)]
+    pub get: i64,
+    #[serde(default)]
+    pub get_empty: i64,
+    #[serde(default)]
+    pub get_no_ack: i64,
+    #[serde(default)]
+    pub publish: i64,
+    #[serde(default)]
+    pub redeliver: i64,
+    #[serde(default)]
+    pub return_unroutable: i64,
+}
@@ -0,0 +1,196 @@
+use crate::api::_generic::{handle_empty_response, handle_response};
+use crate::api::_url::api_url;
+use crate::api::options::pagination::RabbitMqPaginationRequest;
+use crate::api::RabbitMqPaginatedResponse;
+use crate::errors::RabbitMqClientError;
+use crate::RabbitMqClient;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use super::options::RabbitMqRequestOptions;
+
+#[async_trait]
+pub trait ConnectionApi {
+    async fn list_connections(
+        &self,
+        vhost: Option<String>,
+        options: Option<RabbitMqRequestOptions>,
+    ) -> Result<RabbitMqPaginatedResponse<RabbitMqConnection>, RabbitMqClientError>;
+
+    async fn get_connection(&self, name: String)
+        -> Result<RabbitMqConnection, RabbitMqClientError>;
+
+    async fn close_connection(
+        &self,
+        name: String,
+        reason: Option<String>,
+    ) -> Result<(), RabbitMqClientError>;
+
+    async fn close_user_connections(
+        &self,
+        user: String,
+        reason: Option<String>,
+    ) -> Result<(), RabbitMqClientError>;
+}
+
+#[async_trait]
+impl ConnectionApi for RabbitMqClient {
+    #[tracing::instrument(skip(self))]
+    async fn list_connections(
+        &self,
+        vhost: Option<String>,
+        options: Option<RabbitMqRequestOptions>,
+    ) -> Result<RabbitMqPaginatedResponse<RabbitMqConnection>, RabbitMqClientError> {
+        let options: RabbitMqRequestOptions = options.unwrap_or_default();
+        let pagination: RabbitMqPaginationRequest = options.pagination.unwrap_or_default().into();
+
+        let url = match vhost {
+            None => api_url!(self, "/api/connections"),
+            Some(vhost) => api_url!(self, "/api/vhosts/{}/connections", vhost),
+        };
+
+        let response = self
+            .client
+            .request(reqwest::Method::GET, url)
+            .query(&pagination)
+            .query(&options.sorting)
+            .query(&[("disable_stats", options.disable_stats)])
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_connection(
+        &self,
+        name: String,
+    ) -> Result<RabbitMqConnection, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/connections/{}", name),
+            )
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn close_connection(
+        &self,
+        name: String,
+        reason: Option<String>,
+    ) -> Result<(), RabbitMqClientError> {
+        let mut request = self.client.request(
+            reqwest::Method::DELETE,
+            api_url!(self, "/api/connections/{}", name),
+        );
+
+        if let Some(reason) = reason {
+            request = request.header("X-Reason", reason);
+        }
+
+        let response = request.send().await?;
+
+        handle_empty_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn close_user_connections(
+        &self,
+        user: String,
+        reason: Option<String>,
+    ) -> Result<(), RabbitMqClientError> {
+        let mut request = self.client.request(
+            reqwest::Method::DELETE,
+            api_url!(self, "/api/connections/username/{}", user),
+        );
+
+        if let Some(reason) = reason {
+            request = request.header("X-Reason", reason);
+        }
+
+        let response = request.send().await?;
+
+        handle_empty_response(response).await
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqConnection {
+    pub name: String,
+    pub node: String,
+    pub vhost: String,
+    pub user: String,
+    pub user_provided_name: Option<String>,
+    pub protocol: String,
+    pub auth_mechanism: Option<String>,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub state: Option<RabbitMqConnectionState>,
+    pub host: Option<String>,
+    pub port: Option<i64>,
+    pub peer_host: Option<String>,
+    pub peer_port: Option<i64>,
+    #[serde(default, with = "chrono::serde::ts_milliseconds_option")]
+    pub connected_at: Option<DateTime<Utc>>,
+    pub timeout: Option<i64>,
+    pub frame_max: Option<i64>,
+    pub channel_max: Option<i64>,
+    pub channels: Option<i64>,
+    #[serde(default)]
+    pub client_properties: RabbitMqConnectionClientProperties,
+    #[serde(flatten)]
+    pub tls: RabbitMqConnectionTls,
+    pub recv_cnt: Option<i64>,
+    pub recv_oct: Option<i64>,
+    pub send_cnt: Option<i64>,
+    pub send_oct: Option<i64>,
+    pub send_pend: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RabbitMqConnectionState {
+    Starting,
+    Tuning,
+    Opening,
+    Running,
+    Flow,
+    Blocking,
+    Blocked,
+    Closing,
+    Closed,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct RabbitMqConnectionClientProperties {
+    pub connection_name: Option<String>,
+    pub product: Option<String>,
+    pub platform: Option<String>,
+    pub version: Option<String>,
+    pub information: Option<String>,
+    pub copyright: Option<String>,
+    #[serde(default)]
+    pub capabilities: HashMap<String, bool>,
+    #[serde(flatten)]
+    pub extra_properties: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqConnectionTls {
+    #[serde(default)]
+    pub ssl: bool,
+    pub ssl_protocol: Option<String>,
+    pub ssl_key_exchange: Option<String>,
+    pub ssl_cipher: Option<String>,
+    pub ssl_hash: Option<String>,
+    pub peer_cert_subject: Option<String>,
+    pub peer_cert_issuer: Option<String>,
+    pub peer_cert_validity: Option<String>,
+}
@@ -0,0 +1,72 @@
+use crate::api::_generic::handle_response;
+use crate::api::_url::api_url;
+use crate::api::channel::RabbitMqQueueReference;
+use crate::api::queue::RabbitMqArgument;
+use crate::errors::RabbitMqClientError;
+use crate::RabbitMqClient;
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[async_trait]
+pub trait ConsumerApi {
+    async fn list_consumers(
+        &self,
+        vhost: Option<String>,
+    ) -> Result<Vec<RabbitMqConsumer>, RabbitMqClientError>;
+}
+
+#[async_trait]
+impl ConsumerApi for RabbitMqClient {
+    #[tracing::instrument(skip(self))]
+    async fn list_consumers(
+        &self,
+        vhost: Option<String>,
+    ) -> Result<Vec<RabbitMqConsumer>, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/consumers/{}", vhost.unwrap_or_default()),
+            )
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqConsumer {
+    pub consumer_tag: String,
+    pub queue: RabbitMqQueueReference,
+    pub channel_details: RabbitMqConsumerChannelDetails,
+    pub ack_required: bool,
+    pub prefetch_count: i64,
+    pub exclusive: bool,
+    pub active: Option<bool>,
+    pub activity_status: Option<RabbitMqConsumerActivityStatus>,
+    pub consumer_timeout: Option<i64>,
+    #[serde(default)]
+    pub arguments: HashMap<String, RabbitMqArgument>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqConsumerChannelDetails {
+    pub name: String,
+    pub number: i64,
+    pub node: String,
+    pub user: String,
+    pub connection_name: String,
+    pub peer_host: Option<String>,
+    pub peer_port: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RabbitMqConsumerActivityStatus {
+    Up,
+    SingleActive,
+    Waiting,
+    SuspectedDown,
+}
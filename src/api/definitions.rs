@@ -0,0 +1,164 @@
+use crate::api::_generic::{handle_empty_response, handle_response};
+use crate::api::_url::api_url;
+use crate::api::binding::RabbitMqBindingDestinationType;
+use crate::api::global_parameter::RabbitMqGlobalParameter;
+use crate::api::parameter::RabbitMqParameter;
+use crate::api::permission::{RabbitMqPermission, RabbitMqTopicPermission};
+use crate::api::policy::RabbitMqPolicy;
+use crate::api::user::{RabbitMqHashingAlgorithm, RabbitMqUserTag};
+use crate::api::user_limit::RabbitMqUserLimits;
+use crate::api::vhost::RabbitMqVhostMetadata;
+use crate::errors::RabbitMqClientError;
+use crate::RabbitMqClient;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[async_trait]
+pub trait DefinitionsApi {
+    async fn export_definitions(
+        &self,
+        vhost: Option<String>,
+    ) -> Result<RabbitMqDefinitions, RabbitMqClientError>;
+
+    async fn import_definitions(
+        &self,
+        vhost: Option<String>,
+        definitions: RabbitMqDefinitions,
+    ) -> Result<(), RabbitMqClientError>;
+}
+
+#[async_trait]
+impl DefinitionsApi for RabbitMqClient {
+    #[tracing::instrument(skip(self))]
+    async fn export_definitions(
+        &self,
+        vhost: Option<String>,
+    ) -> Result<RabbitMqDefinitions, RabbitMqClientError> {
+        let url = match vhost {
+            None => api_url!(self, "/api/definitions"),
+            Some(vhost) => api_url!(self, "/api/definitions/{}", vhost),
+        };
+
+        let response = self
+            .client
+            .request(reqwest::Method::GET, url)
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+
+    #[tracing::instrument(skip(self, definitions))]
+    async fn import_definitions(
+        &self,
+        vhost: Option<String>,
+        definitions: RabbitMqDefinitions,
+    ) -> Result<(), RabbitMqClientError> {
+        let url = match vhost {
+            None => api_url!(self, "/api/definitions"),
+            Some(vhost) => api_url!(self, "/api/definitions/{}", vhost),
+        };
+
+        let response = self
+            .client
+            .request(reqwest::Method::POST, url)
+            .json(&definitions)
+            .send()
+            .await?;
+
+        handle_empty_response(response).await
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RabbitMqDefinitions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rabbit_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rabbitmq_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_version: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub users: Vec<RabbitMqDefinitionsUser>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub vhosts: Vec<RabbitMqDefinitionsVhost>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub permissions: Vec<RabbitMqPermission>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub topic_permissions: Vec<RabbitMqTopicPermission>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parameters: Vec<RabbitMqParameter>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub global_parameters: Vec<RabbitMqGlobalParameter>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub policies: Vec<RabbitMqPolicy>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub queues: Vec<RabbitMqDefinitionsQueue>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exchanges: Vec<RabbitMqDefinitionsExchange>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bindings: Vec<RabbitMqDefinitionsBinding>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RabbitMqDefinitionsUser {
+    pub name: String,
+    pub password_hash: String,
+    pub hashing_algorithm: RabbitMqHashingAlgorithm,
+    pub tags: Vec<RabbitMqUserTag>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limits: Option<RabbitMqUserLimits>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RabbitMqDefinitionsVhost {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_queue_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<RabbitMqVhostMetadata>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RabbitMqDefinitionsQueue {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vhost: Option<String>,
+    pub durable: bool,
+    pub auto_delete: bool,
+    #[serde(default)]
+    pub arguments: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RabbitMqDefinitionsExchange {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vhost: Option<String>,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub durable: bool,
+    pub auto_delete: bool,
+    pub internal: bool,
+    #[serde(default)]
+    pub arguments: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RabbitMqDefinitionsBinding {
+    pub source: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vhost: Option<String>,
+    pub destination: String,
+    pub destination_type: RabbitMqBindingDestinationType,
+    pub routing_key: String,
+    #[serde(default)]
+    pub arguments: HashMap<String, serde_json::Value>,
+}
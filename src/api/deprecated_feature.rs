@@ -0,0 +1,70 @@
+use crate::api::_generic::handle_response;
+use crate::api::_url::api_url;
+use crate::errors::RabbitMqClientError;
+use crate::RabbitMqClient;
+use async_trait::async_trait;
+use serde::Deserialize;
+
+#[async_trait]
+pub trait DeprecatedFeatureApi {
+    async fn list_deprecated_features(
+        &self,
+    ) -> Result<Vec<RabbitMqDeprecatedFeature>, RabbitMqClientError>;
+
+    async fn list_deprecated_features_in_use(
+        &self,
+    ) -> Result<Vec<RabbitMqDeprecatedFeature>, RabbitMqClientError>;
+}
+
+#[async_trait]
+impl DeprecatedFeatureApi for RabbitMqClient {
+    #[tracing::instrument(skip(self))]
+    async fn list_deprecated_features(
+        &self,
+    ) -> Result<Vec<RabbitMqDeprecatedFeature>, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/deprecated-features"),
+            )
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_deprecated_features_in_use(
+        &self,
+    ) -> Result<Vec<RabbitMqDeprecatedFeature>, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/deprecated-features/used"),
+            )
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqDeprecatedFeature {
+    pub name: String,
+    pub desc: String,
+    pub doc_url: String,
+    pub deprecation_phase: RabbitMqDeprecationPhase,
+    pub provided_by: String,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RabbitMqDeprecationPhase {
+    PermittedByDefault,
+    DeniedByDefault,
+    Disconnected,
+    Removed,
+}
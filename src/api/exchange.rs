@@ -1,10 +1,13 @@
 use crate::api::_generic::{handle_empty_response, handle_response};
+use crate::api::_url::api_url;
 use crate::api::binding::RabbitMqBinding;
+use crate::api::queue::RabbitMqArgument;
 use crate::api::{RabbitMqPaginatedResponse, RabbitMqPagination, RabbitMqPaginationFilter};
 use crate::errors::RabbitMqClientError;
 use crate::RabbitMqClient;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 use super::options::pagination::RabbitMqPaginationRequest;
 use super::options::RabbitMqRequestOptions;
@@ -70,11 +73,7 @@ impl ExchangeApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::GET,
-                format!(
-                    "{}/api/exchanges/{}",
-                    self.api_url,
-                    vhost.unwrap_or_default()
-                ),
+                api_url!(self, "/api/exchanges/{}", vhost.unwrap_or_default()),
             )
             .query(&pagination)
             .query(&options.sorting)
@@ -94,7 +93,7 @@ impl ExchangeApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::GET,
-                format!("{}/api/exchanges/{}/{}", self.api_url, vhost, exchange),
+                api_url!(self, "/api/exchanges/{}/{}", vhost, exchange),
             )
             .send()
             .await?;
@@ -144,7 +143,7 @@ impl ExchangeApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::PUT,
-                format!("{}/api/exchanges/{}/{}", self.api_url, vhost, exchange),
+                api_url!(self, "/api/exchanges/{}/{}", vhost, exchange),
             )
             .json(&request)
             .send()
@@ -162,7 +161,7 @@ impl ExchangeApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::DELETE,
-                format!("{}/api/exchanges/{}/{}", self.api_url, vhost, exchange),
+                api_url!(self, "/api/exchanges/{}/{}", vhost, exchange),
             )
             .send()
             .await?;
@@ -179,9 +178,11 @@ impl ExchangeApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::GET,
-                format!(
-                    "{}/api/exchanges/{}/{}/bindings/source",
-                    self.api_url, vhost, exchange
+                api_url!(
+                    self,
+                    "/api/exchanges/{}/{}/bindings/source",
+                    vhost,
+                    exchange
                 ),
             )
             .send()
@@ -199,9 +200,11 @@ impl ExchangeApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::GET,
-                format!(
-                    "{}/api/exchanges/{}/{}/bindings/destination",
-                    self.api_url, vhost, exchange
+                api_url!(
+                    self,
+                    "/api/exchanges/{}/{}/bindings/destination",
+                    vhost,
+                    exchange
                 ),
             )
             .send()
@@ -221,6 +224,8 @@ pub struct RabbitMqExchange {
     pub kind: String,
     pub user_who_performed_action: String,
     pub vhost: String,
+    #[serde(default)]
+    pub arguments: HashMap<String, RabbitMqArgument>,
     pub message_stats: Option<RabbitMqExchangeMessageStats>,
 }
 
@@ -230,11 +235,13 @@ pub struct RabbitMqExchangeMessageStats {
     pub publish_out: Option<i64>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Default, Serialize)]
 pub struct RabbitMqExchangeRequest {
     #[serde(rename = "type")]
     pub kind: String,
     pub auto_delete: bool,
     pub durable: bool,
     pub internal: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arguments: Option<HashMap<String, RabbitMqArgument>>,
 }
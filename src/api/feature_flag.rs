@@ -0,0 +1,87 @@
+use crate::api::_generic::{handle_empty_response, handle_response};
+use crate::api::_url::api_url;
+use crate::errors::RabbitMqClientError;
+use crate::RabbitMqClient;
+use async_trait::async_trait;
+use serde::Deserialize;
+
+#[async_trait]
+pub trait FeatureFlagApi {
+    async fn list_feature_flags(&self) -> Result<Vec<RabbitMqFeatureFlag>, RabbitMqClientError>;
+
+    async fn enable_feature_flag(&self, name: String) -> Result<(), RabbitMqClientError>;
+
+    async fn enable_all_stable_feature_flags(&self) -> Result<(), RabbitMqClientError>;
+}
+
+#[async_trait]
+impl FeatureFlagApi for RabbitMqClient {
+    #[tracing::instrument(skip(self))]
+    async fn list_feature_flags(&self) -> Result<Vec<RabbitMqFeatureFlag>, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(reqwest::Method::GET, api_url!(self, "/api/feature-flags"))
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn enable_feature_flag(&self, name: String) -> Result<(), RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::PUT,
+                api_url!(self, "/api/feature-flags/{}/enable", name),
+            )
+            .json(&serde_json::json!({}))
+            .send()
+            .await?;
+
+        handle_empty_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn enable_all_stable_feature_flags(&self) -> Result<(), RabbitMqClientError> {
+        let flags = self.list_feature_flags().await?;
+
+        for flag in flags.into_iter().filter(|f| {
+            f.stability == RabbitMqFeatureFlagStability::Stable
+                && f.state == RabbitMqFeatureFlagState::Disabled
+        }) {
+            self.enable_feature_flag(flag.name).await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqFeatureFlag {
+    pub name: String,
+    pub desc: String,
+    pub doc_url: String,
+    pub stability: RabbitMqFeatureFlagStability,
+    pub state: RabbitMqFeatureFlagState,
+    pub provided_by: String,
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RabbitMqFeatureFlagStability {
+    Required,
+    Stable,
+    Experimental,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RabbitMqFeatureFlagState {
+    Enabled,
+    Disabled,
+    StateChanging,
+    Unavailable,
+}
@@ -0,0 +1,357 @@
+use crate::api::_generic::{deserialize_uris, handle_empty_response, handle_response};
+use crate::api::_url::api_url;
+use crate::api::parameter::{ParameterApi, RabbitMqParameter, RabbitMqParameterRequest};
+use crate::errors::RabbitMqClientError;
+use crate::RabbitMqClient;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+const FEDERATION_UPSTREAM_COMPONENT: &str = "federation-upstream";
+const FEDERATION_UPSTREAM_SET_COMPONENT: &str = "federation-upstream-set";
+
+#[async_trait]
+pub trait FederationApi {
+    async fn list_federation_upstreams(
+        &self,
+        vhost: Option<String>,
+    ) -> Result<Vec<RabbitMqParameter<RabbitMqFederationUpstream>>, RabbitMqClientError>;
+
+    async fn get_federation_upstream(
+        &self,
+        vhost: String,
+        name: String,
+    ) -> Result<RabbitMqParameter<RabbitMqFederationUpstream>, RabbitMqClientError>;
+
+    async fn create_federation_upstream(
+        &self,
+        vhost: String,
+        name: String,
+        upstream: RabbitMqFederationUpstream,
+    ) -> Result<(), RabbitMqClientError>;
+
+    async fn update_federation_upstream(
+        &self,
+        vhost: String,
+        name: String,
+        upstream: RabbitMqFederationUpstream,
+    ) -> Result<(), RabbitMqClientError>;
+
+    async fn delete_federation_upstream(
+        &self,
+        vhost: String,
+        name: String,
+    ) -> Result<(), RabbitMqClientError>;
+
+    async fn list_federation_upstream_sets(
+        &self,
+        vhost: Option<String>,
+    ) -> Result<Vec<RabbitMqParameter<Vec<RabbitMqFederationUpstreamSetMember>>>, RabbitMqClientError>;
+
+    async fn get_federation_upstream_set(
+        &self,
+        vhost: String,
+        name: String,
+    ) -> Result<RabbitMqParameter<Vec<RabbitMqFederationUpstreamSetMember>>, RabbitMqClientError>;
+
+    async fn create_federation_upstream_set(
+        &self,
+        vhost: String,
+        name: String,
+        members: Vec<RabbitMqFederationUpstreamSetMember>,
+    ) -> Result<(), RabbitMqClientError>;
+
+    async fn update_federation_upstream_set(
+        &self,
+        vhost: String,
+        name: String,
+        members: Vec<RabbitMqFederationUpstreamSetMember>,
+    ) -> Result<(), RabbitMqClientError>;
+
+    async fn delete_federation_upstream_set(
+        &self,
+        vhost: String,
+        name: String,
+    ) -> Result<(), RabbitMqClientError>;
+
+    async fn list_federation_links(
+        &self,
+        vhost: Option<String>,
+    ) -> Result<Vec<RabbitMqFederationLink>, RabbitMqClientError>;
+
+    async fn restart_federation_link(
+        &self,
+        vhost: String,
+        id: String,
+        node: String,
+    ) -> Result<(), RabbitMqClientError>;
+}
+
+#[async_trait]
+impl FederationApi for RabbitMqClient {
+    #[tracing::instrument(skip(self))]
+    async fn list_federation_upstreams(
+        &self,
+        vhost: Option<String>,
+    ) -> Result<Vec<RabbitMqParameter<RabbitMqFederationUpstream>>, RabbitMqClientError> {
+        match vhost {
+            None => {
+                self.list_parameters(Some(FEDERATION_UPSTREAM_COMPONENT.to_string()))
+                    .await
+            }
+            Some(vhost) => {
+                self.list_vhost_parameters(FEDERATION_UPSTREAM_COMPONENT.to_string(), vhost)
+                    .await
+            }
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_federation_upstream(
+        &self,
+        vhost: String,
+        name: String,
+    ) -> Result<RabbitMqParameter<RabbitMqFederationUpstream>, RabbitMqClientError> {
+        self.get_parameter(FEDERATION_UPSTREAM_COMPONENT.to_string(), vhost, name)
+            .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn create_federation_upstream(
+        &self,
+        vhost: String,
+        name: String,
+        upstream: RabbitMqFederationUpstream,
+    ) -> Result<(), RabbitMqClientError> {
+        self.create_parameter(
+            FEDERATION_UPSTREAM_COMPONENT.to_string(),
+            vhost,
+            name,
+            RabbitMqParameterRequest { value: upstream },
+        )
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn update_federation_upstream(
+        &self,
+        vhost: String,
+        name: String,
+        upstream: RabbitMqFederationUpstream,
+    ) -> Result<(), RabbitMqClientError> {
+        self.update_parameter(
+            FEDERATION_UPSTREAM_COMPONENT.to_string(),
+            vhost,
+            name,
+            RabbitMqParameterRequest { value: upstream },
+        )
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_federation_upstream(
+        &self,
+        vhost: String,
+        name: String,
+    ) -> Result<(), RabbitMqClientError> {
+        self.delete_parameter(FEDERATION_UPSTREAM_COMPONENT.to_string(), vhost, name)
+            .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_federation_upstream_sets(
+        &self,
+        vhost: Option<String>,
+    ) -> Result<Vec<RabbitMqParameter<Vec<RabbitMqFederationUpstreamSetMember>>>, RabbitMqClientError>
+    {
+        match vhost {
+            None => {
+                self.list_parameters(Some(FEDERATION_UPSTREAM_SET_COMPONENT.to_string()))
+                    .await
+            }
+            Some(vhost) => {
+                self.list_vhost_parameters(FEDERATION_UPSTREAM_SET_COMPONENT.to_string(), vhost)
+                    .await
+            }
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_federation_upstream_set(
+        &self,
+        vhost: String,
+        name: String,
+    ) -> Result<RabbitMqParameter<Vec<RabbitMqFederationUpstreamSetMember>>, RabbitMqClientError>
+    {
+        self.get_parameter(FEDERATION_UPSTREAM_SET_COMPONENT.to_string(), vhost, name)
+            .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn create_federation_upstream_set(
+        &self,
+        vhost: String,
+        name: String,
+        members: Vec<RabbitMqFederationUpstreamSetMember>,
+    ) -> Result<(), RabbitMqClientError> {
+        self.create_parameter(
+            FEDERATION_UPSTREAM_SET_COMPONENT.to_string(),
+            vhost,
+            name,
+            RabbitMqParameterRequest { value: members },
+        )
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn update_federation_upstream_set(
+        &self,
+        vhost: String,
+        name: String,
+        members: Vec<RabbitMqFederationUpstreamSetMember>,
+    ) -> Result<(), RabbitMqClientError> {
+        self.update_parameter(
+            FEDERATION_UPSTREAM_SET_COMPONENT.to_string(),
+            vhost,
+            name,
+            RabbitMqParameterRequest { value: members },
+        )
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_federation_upstream_set(
+        &self,
+        vhost: String,
+        name: String,
+    ) -> Result<(), RabbitMqClientError> {
+        self.delete_parameter(FEDERATION_UPSTREAM_SET_COMPONENT.to_string(), vhost, name)
+            .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_federation_links(
+        &self,
+        vhost: Option<String>,
+    ) -> Result<Vec<RabbitMqFederationLink>, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/federation-links/{}", vhost.unwrap_or_default()),
+            )
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn restart_federation_link(
+        &self,
+        vhost: String,
+        id: String,
+        node: String,
+    ) -> Result<(), RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::DELETE,
+                api_url!(
+                    self,
+                    "/api/federation-links/vhost/{}/{}/{}/restart",
+                    vhost,
+                    id,
+                    node
+                ),
+            )
+            .send()
+            .await?;
+
+        handle_empty_response(response).await
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct RabbitMqFederationUpstream {
+    #[serde(deserialize_with = "deserialize_uris")]
+    pub uri: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefetch_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reconnect_delay: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ack_mode: Option<RabbitMqFederationAckMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trust_user_id: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exchange: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_hops: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_ttl: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consumer_tag: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RabbitMqFederationAckMode {
+    OnConfirm,
+    OnPublish,
+    NoAck,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RabbitMqFederationUpstreamSetMember {
+    pub upstream: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exchange: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqFederationLink {
+    pub id: Option<String>,
+    pub node: String,
+    pub vhost: String,
+    pub upstream: String,
+    #[serde(rename = "type")]
+    pub kind: RabbitMqFederationLinkType,
+    pub exchange: Option<String>,
+    pub upstream_exchange: Option<String>,
+    pub queue: Option<String>,
+    pub upstream_queue: Option<String>,
+    pub uri: Option<String>,
+    pub local_connection: Option<String>,
+    pub timestamp: Option<String>,
+    #[serde(flatten)]
+    pub status: RabbitMqFederationLinkStatus,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RabbitMqFederationLinkType {
+    Exchange,
+    Queue,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum RabbitMqFederationLinkStatus {
+    Starting,
+    Running,
+    Error {
+        #[serde(rename = "error")]
+        reason: String,
+    },
+    #[serde(other)]
+    Unknown,
+}
@@ -0,0 +1,106 @@
+use crate::api::_generic::{handle_empty_response, handle_response};
+use crate::api::_url::api_url;
+use crate::errors::RabbitMqClientError;
+use crate::RabbitMqClient;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+#[async_trait]
+pub trait GlobalParameterApi {
+    async fn list_global_parameters(
+        &self,
+    ) -> Result<Vec<RabbitMqGlobalParameter>, RabbitMqClientError>;
+
+    async fn get_global_parameter(
+        &self,
+        name: String,
+    ) -> Result<RabbitMqGlobalParameter, RabbitMqClientError>;
+
+    async fn set_global_parameter(
+        &self,
+        name: String,
+        request: RabbitMqGlobalParameterRequest,
+    ) -> Result<(), RabbitMqClientError>;
+
+    async fn delete_global_parameter(&self, name: String) -> Result<(), RabbitMqClientError>;
+}
+
+#[async_trait]
+impl GlobalParameterApi for RabbitMqClient {
+    #[tracing::instrument(skip(self))]
+    async fn list_global_parameters(
+        &self,
+    ) -> Result<Vec<RabbitMqGlobalParameter>, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/global-parameters"),
+            )
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_global_parameter(
+        &self,
+        name: String,
+    ) -> Result<RabbitMqGlobalParameter, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/global-parameters/{}", name),
+            )
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn set_global_parameter(
+        &self,
+        name: String,
+        request: RabbitMqGlobalParameterRequest,
+    ) -> Result<(), RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::PUT,
+                api_url!(self, "/api/global-parameters/{}", name),
+            )
+            .json(&request)
+            .send()
+            .await?;
+
+        handle_empty_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_global_parameter(&self, name: String) -> Result<(), RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::DELETE,
+                api_url!(self, "/api/global-parameters/{}", name),
+            )
+            .send()
+            .await?;
+
+        handle_empty_response(response).await
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RabbitMqGlobalParameter {
+    pub name: String,
+    pub value: serde_json::Value,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RabbitMqGlobalParameterRequest {
+    pub value: serde_json::Value,
+}
@@ -0,0 +1,340 @@
+use crate::api::_generic::map_error;
+use crate::api::_url::api_url;
+use crate::errors::RabbitMqClientError;
+use crate::RabbitMqClient;
+use async_trait::async_trait;
+use http::StatusCode;
+use reqwest::Response;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::fmt::{Display, Formatter};
+
+#[async_trait]
+pub trait HealthCheckApi {
+    async fn check_alarms(
+        &self,
+    ) -> Result<RabbitMqHealthCheck<RabbitMqHealthCheckAlarms>, RabbitMqClientError>;
+
+    async fn check_local_alarms(
+        &self,
+    ) -> Result<RabbitMqHealthCheck<RabbitMqHealthCheckAlarms>, RabbitMqClientError>;
+
+    async fn check_certificate_expiration(
+        &self,
+        within: u32,
+        unit: RabbitMqHealthCheckTimeUnit,
+    ) -> Result<RabbitMqHealthCheck<RabbitMqHealthCheckExpiringCertificates>, RabbitMqClientError>;
+
+    async fn check_port_listener(
+        &self,
+        port: u16,
+    ) -> Result<RabbitMqHealthCheck<RabbitMqHealthCheckMissingPort>, RabbitMqClientError>;
+
+    async fn check_protocol_listener(
+        &self,
+        protocol: String,
+    ) -> Result<RabbitMqHealthCheck<RabbitMqHealthCheckMissingProtocol>, RabbitMqClientError>;
+
+    async fn check_virtual_hosts(
+        &self,
+    ) -> Result<RabbitMqHealthCheck<RabbitMqHealthCheckVirtualHosts>, RabbitMqClientError>;
+
+    async fn check_node_is_quorum_critical(
+        &self,
+    ) -> Result<RabbitMqHealthCheck<RabbitMqHealthCheckCriticalQueues>, RabbitMqClientError>;
+
+    async fn check_node_is_mirror_sync_critical(
+        &self,
+    ) -> Result<RabbitMqHealthCheck<RabbitMqHealthCheckCriticalQueues>, RabbitMqClientError>;
+
+    async fn check_ready_to_serve_clients(
+        &self,
+    ) -> Result<RabbitMqHealthCheck<()>, RabbitMqClientError>;
+}
+
+#[async_trait]
+impl HealthCheckApi for RabbitMqClient {
+    #[tracing::instrument(skip(self))]
+    async fn check_alarms(
+        &self,
+    ) -> Result<RabbitMqHealthCheck<RabbitMqHealthCheckAlarms>, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/health/checks/alarms"),
+            )
+            .send()
+            .await?;
+
+        handle_health_check_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn check_local_alarms(
+        &self,
+    ) -> Result<RabbitMqHealthCheck<RabbitMqHealthCheckAlarms>, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/health/checks/local-alarms"),
+            )
+            .send()
+            .await?;
+
+        handle_health_check_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn check_certificate_expiration(
+        &self,
+        within: u32,
+        unit: RabbitMqHealthCheckTimeUnit,
+    ) -> Result<RabbitMqHealthCheck<RabbitMqHealthCheckExpiringCertificates>, RabbitMqClientError>
+    {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(
+                    self,
+                    "/api/health/checks/certificate-expiration/{}/{}",
+                    within,
+                    unit
+                ),
+            )
+            .send()
+            .await?;
+
+        handle_health_check_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn check_port_listener(
+        &self,
+        port: u16,
+    ) -> Result<RabbitMqHealthCheck<RabbitMqHealthCheckMissingPort>, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/health/checks/port-listener/{}", port),
+            )
+            .send()
+            .await?;
+
+        handle_health_check_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn check_protocol_listener(
+        &self,
+        protocol: String,
+    ) -> Result<RabbitMqHealthCheck<RabbitMqHealthCheckMissingProtocol>, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/health/checks/protocol-listener/{}", protocol),
+            )
+            .send()
+            .await?;
+
+        handle_health_check_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn check_virtual_hosts(
+        &self,
+    ) -> Result<RabbitMqHealthCheck<RabbitMqHealthCheckVirtualHosts>, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/health/checks/virtual-hosts"),
+            )
+            .send()
+            .await?;
+
+        handle_health_check_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn check_node_is_quorum_critical(
+        &self,
+    ) -> Result<RabbitMqHealthCheck<RabbitMqHealthCheckCriticalQueues>, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/health/checks/node-is-quorum-critical"),
+            )
+            .send()
+            .await?;
+
+        handle_health_check_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn check_node_is_mirror_sync_critical(
+        &self,
+    ) -> Result<RabbitMqHealthCheck<RabbitMqHealthCheckCriticalQueues>, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/health/checks/node-is-mirror-sync-critical"),
+            )
+            .send()
+            .await?;
+
+        handle_health_check_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn check_ready_to_serve_clients(
+        &self,
+    ) -> Result<RabbitMqHealthCheck<()>, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/health/checks/ready-to-serve-clients"),
+            )
+            .send()
+            .await?;
+
+        handle_health_check_response(response).await
+    }
+}
+
+#[tracing::instrument(skip(response))]
+async fn handle_health_check_response<T>(
+    response: Response,
+) -> Result<RabbitMqHealthCheck<T>, RabbitMqClientError>
+where
+    T: DeserializeOwned,
+{
+    let status = response.status();
+
+    if status.is_success() {
+        return Ok(RabbitMqHealthCheck::Passed);
+    }
+
+    let text = response
+        .text()
+        .await
+        .map_err(RabbitMqClientError::ResponseError)?;
+
+    if status.eq(&StatusCode::SERVICE_UNAVAILABLE) {
+        match serde_json::from_str::<RabbitMqHealthCheckFailure<T>>(&text) {
+            Ok(failure) => Ok(RabbitMqHealthCheck::Failed(failure)),
+            Err(e) => Err(RabbitMqClientError::JSONError(e)),
+        }
+    } else {
+        Err(map_error(status, text))
+    }
+}
+
+#[derive(Debug)]
+pub enum RabbitMqHealthCheck<T> {
+    Passed,
+    Failed(RabbitMqHealthCheckFailure<T>),
+}
+
+impl<T> RabbitMqHealthCheck<T> {
+    pub fn is_passed(&self) -> bool {
+        matches!(self, RabbitMqHealthCheck::Passed)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqHealthCheckFailure<T> {
+    pub reason: String,
+    #[serde(flatten)]
+    pub details: T,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqHealthCheckAlarms {
+    #[serde(default)]
+    pub alarms: Vec<RabbitMqHealthCheckAlarm>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqHealthCheckAlarm {
+    pub node: String,
+    pub resource: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqHealthCheckExpiringCertificates {
+    #[serde(default)]
+    pub expired: Vec<RabbitMqHealthCheckCertificate>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqHealthCheckCertificate {
+    pub node: String,
+    pub protocol: String,
+    pub interface: Option<String>,
+    pub port: Option<u16>,
+    pub certfile: Option<String>,
+    pub cacertfile: Option<String>,
+    pub expiry_date: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqHealthCheckMissingPort {
+    pub missing: u16,
+    #[serde(default)]
+    pub ports: Vec<u16>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqHealthCheckMissingProtocol {
+    pub missing: String,
+    #[serde(default)]
+    pub protocols: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqHealthCheckVirtualHosts {
+    #[serde(default, rename = "virtual-hosts")]
+    pub virtual_hosts: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqHealthCheckCriticalQueues {
+    #[serde(default)]
+    pub queues: Vec<RabbitMqHealthCheckQueue>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqHealthCheckQueue {
+    pub name: String,
+    pub readable_name: Option<String>,
+    pub virtual_host: String,
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum RabbitMqHealthCheckTimeUnit {
+    Days,
+    Weeks,
+    Months,
+    Years,
+}
+
+impl Display for RabbitMqHealthCheckTimeUnit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RabbitMqHealthCheckTimeUnit::Days => write!(f, "days"),
+            RabbitMqHealthCheckTimeUnit::Weeks => write!(f, "weeks"),
+            RabbitMqHealthCheckTimeUnit::Months => write!(f, "months"),
+            RabbitMqHealthCheckTimeUnit::Years => write!(f, "years"),
+        }
+    }
+}
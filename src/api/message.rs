@@ -1,4 +1,5 @@
 use crate::api::_generic::handle_response;
+use crate::api::_url::api_url;
 use crate::errors::RabbitMqClientError;
 use crate::RabbitMqClient;
 use async_trait::async_trait;
@@ -37,10 +38,7 @@ impl MessageApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::POST,
-                format!(
-                    "{}/api/exchanges/{}/{}/publish",
-                    self.api_url, vhost, exchange
-                ),
+                api_url!(self, "/api/exchanges/{}/{}/publish", vhost, exchange),
             )
             .json(&request)
             .send()
@@ -60,7 +58,7 @@ impl MessageApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::POST,
-                format!("{}/api/queues/{}/{}/get", self.api_url, vhost, queue),
+                api_url!(self, "/api/queues/{}/{}/get", vhost, queue),
             )
             .json(&options)
             .send()
@@ -1,15 +1,34 @@
 mod _generic;
+mod _url;
+pub mod auth_attempt;
 pub mod binding;
+pub mod channel;
+pub mod connection;
+pub mod consumer;
+pub mod definitions;
+pub mod deprecated_feature;
 pub mod exchange;
+pub mod feature_flag;
+pub mod federation;
+pub mod global_parameter;
+pub mod health_check;
 pub mod message;
 pub mod node;
+pub mod operator_policy;
 mod options;
 pub mod overview;
+pub mod parameter;
 pub mod permission;
 pub mod policy;
 pub mod queue;
+pub mod rebalance;
+pub mod shovel;
+pub mod stream;
+pub mod super_stream;
 pub mod user;
+pub mod user_limit;
 pub mod vhost;
+pub mod vhost_limit;
 
 pub use options::{
     pagination::{RabbitMqPaginatedResponse, RabbitMqPagination, RabbitMqPaginationFilter},
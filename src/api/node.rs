@@ -1,4 +1,5 @@
 use crate::api::_generic::handle_response;
+use crate::api::_url::api_url;
 use crate::api::overview::{RabbitMqContext, RabbitMqExchangeType};
 use crate::errors::RabbitMqClientError;
 use crate::RabbitMqClient;
@@ -22,7 +23,7 @@ impl NodeApi for RabbitMqClient {
     async fn list_nodes(&self) -> Result<Vec<RabbitMqNode>, RabbitMqClientError> {
         let response = self
             .client
-            .request(reqwest::Method::GET, format!("{}/api/nodes", self.api_url))
+            .request(reqwest::Method::GET, api_url!(self, "/api/nodes"))
             .send()
             .await?;
 
@@ -32,10 +33,7 @@ impl NodeApi for RabbitMqClient {
     async fn get_node(&self, node: String) -> Result<RabbitMqNode, RabbitMqClientError> {
         let response = self
             .client
-            .request(
-                reqwest::Method::GET,
-                format!("{}/api/nodes/{}", self.api_url, node),
-            )
+            .request(reqwest::Method::GET, api_url!(self, "/api/nodes/{}", node))
             .send()
             .await?;
 
@@ -50,7 +48,7 @@ impl NodeApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::GET,
-                format!("{}/api/nodes/{}/memory", self.api_url, node),
+                api_url!(self, "/api/nodes/{}/memory", node),
             )
             .send()
             .await?;
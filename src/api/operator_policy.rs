@@ -0,0 +1,178 @@
+use crate::api::_generic::{handle_empty_response, handle_response};
+use crate::api::_url::api_url;
+use crate::errors::RabbitMqClientError;
+use crate::RabbitMqClient;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+#[async_trait]
+pub trait OperatorPolicyApi {
+    async fn list_operator_policies(
+        &self,
+        vhost: Option<String>,
+    ) -> Result<Vec<RabbitMqOperatorPolicy>, RabbitMqClientError>;
+
+    async fn get_operator_policy(
+        &self,
+        vhost: String,
+        policy: String,
+    ) -> Result<RabbitMqOperatorPolicy, RabbitMqClientError>;
+
+    async fn create_operator_policy(
+        &self,
+        vhost: String,
+        policy: String,
+        request: RabbitMqOperatorPolicyRequest,
+    ) -> Result<(), RabbitMqClientError>;
+
+    async fn update_operator_policy(
+        &self,
+        vhost: String,
+        policy: String,
+        request: RabbitMqOperatorPolicyRequest,
+    ) -> Result<(), RabbitMqClientError>;
+
+    async fn delete_operator_policy(
+        &self,
+        vhost: String,
+        policy: String,
+    ) -> Result<(), RabbitMqClientError>;
+}
+
+#[async_trait]
+impl OperatorPolicyApi for RabbitMqClient {
+    async fn list_operator_policies(
+        &self,
+        vhost: Option<String>,
+    ) -> Result<Vec<RabbitMqOperatorPolicy>, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/operator-policies/{}", vhost.unwrap_or_default()),
+            )
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+
+    async fn get_operator_policy(
+        &self,
+        vhost: String,
+        policy: String,
+    ) -> Result<RabbitMqOperatorPolicy, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/operator-policies/{}/{}", vhost, policy),
+            )
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+
+    async fn create_operator_policy(
+        &self,
+        vhost: String,
+        policy: String,
+        request: RabbitMqOperatorPolicyRequest,
+    ) -> Result<(), RabbitMqClientError> {
+        let policies = self.list_operator_policies(Some(vhost.clone())).await?;
+        if let Some(existing) = policies.iter().find(|v| v.name == policy) {
+            return Err(RabbitMqClientError::AlreadyExists(format!(
+                "{} operator policy",
+                existing.name
+            )));
+        }
+
+        self.update_operator_policy(vhost, policy, request).await
+    }
+
+    async fn update_operator_policy(
+        &self,
+        vhost: String,
+        policy: String,
+        request: RabbitMqOperatorPolicyRequest,
+    ) -> Result<(), RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::PUT,
+                api_url!(self, "/api/operator-policies/{}/{}", vhost, policy),
+            )
+            .json(&request)
+            .send()
+            .await?;
+
+        handle_empty_response(response).await
+    }
+
+    async fn delete_operator_policy(
+        &self,
+        vhost: String,
+        policy: String,
+    ) -> Result<(), RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::DELETE,
+                api_url!(self, "/api/operator-policies/{}/{}", vhost, policy),
+            )
+            .send()
+            .await?;
+
+        handle_empty_response(response).await
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqOperatorPolicy {
+    pub name: String,
+    pub vhost: String,
+    pub pattern: String,
+    #[serde(rename = "apply-to")]
+    pub apply_to: String,
+    pub definition: RabbitMqOperatorPolicyDefinition,
+    pub priority: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RabbitMqOperatorPolicyRequest {
+    pub pattern: String,
+    pub definition: RabbitMqOperatorPolicyDefinition,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i64>,
+    #[serde(rename = "apply-to")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub apply_to: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct RabbitMqOperatorPolicyDefinition {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length_bytes: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_ttl: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overflow: Option<RabbitMqOperatorPolicyOverflow>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue_version: Option<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RabbitMqOperatorPolicyOverflow {
+    DropHead,
+    RejectPublish,
+    RejectPublishDlx,
+}
@@ -1,4 +1,5 @@
 use crate::api::_generic::{handle_empty_response, handle_response};
+use crate::api::_url::api_url;
 use crate::errors::RabbitMqClientError;
 use crate::RabbitMqClient;
 use async_trait::async_trait;
@@ -21,10 +22,7 @@ impl OverviewApi for RabbitMqClient {
     async fn get_overview(&self) -> Result<RabbitMqOverview, RabbitMqClientError> {
         let response = self
             .client
-            .request(
-                reqwest::Method::GET,
-                format!("{}/api/overview", self.api_url),
-            )
+            .request(reqwest::Method::GET, api_url!(self, "/api/overview"))
             .send()
             .await?;
 
@@ -34,10 +32,7 @@ impl OverviewApi for RabbitMqClient {
     async fn get_cluster_name(&self) -> Result<RabbitMqClusterName, RabbitMqClientError> {
         let response = self
             .client
-            .request(
-                reqwest::Method::GET,
-                format!("{}/api/cluster-name", self.api_url),
-            )
+            .request(reqwest::Method::GET, api_url!(self, "/api/cluster-name"))
             .send()
             .await?;
 
@@ -50,10 +45,7 @@ impl OverviewApi for RabbitMqClient {
     ) -> Result<(), RabbitMqClientError> {
         let response = self
             .client
-            .request(
-                reqwest::Method::PUT,
-                format!("{}/api/cluster-name", self.api_url),
-            )
+            .request(reqwest::Method::PUT, api_url!(self, "/api/cluster-name"))
             .json(&request)
             .send()
             .await?;
@@ -0,0 +1,212 @@
+use crate::api::_generic::{handle_empty_response, handle_response};
+use crate::api::_url::api_url;
+use crate::errors::RabbitMqClientError;
+use crate::RabbitMqClient;
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+#[async_trait]
+pub trait ParameterApi {
+    async fn list_parameters<T>(
+        &self,
+        component: Option<String>,
+    ) -> Result<Vec<RabbitMqParameter<T>>, RabbitMqClientError>
+    where
+        T: DeserializeOwned;
+
+    async fn list_vhost_parameters<T>(
+        &self,
+        component: String,
+        vhost: String,
+    ) -> Result<Vec<RabbitMqParameter<T>>, RabbitMqClientError>
+    where
+        T: DeserializeOwned;
+
+    async fn get_parameter<T>(
+        &self,
+        component: String,
+        vhost: String,
+        name: String,
+    ) -> Result<RabbitMqParameter<T>, RabbitMqClientError>
+    where
+        T: DeserializeOwned;
+
+    async fn create_parameter<T>(
+        &self,
+        component: String,
+        vhost: String,
+        name: String,
+        request: RabbitMqParameterRequest<T>,
+    ) -> Result<(), RabbitMqClientError>
+    where
+        T: Serialize + Send + Sync + std::fmt::Debug;
+
+    async fn update_parameter<T>(
+        &self,
+        component: String,
+        vhost: String,
+        name: String,
+        request: RabbitMqParameterRequest<T>,
+    ) -> Result<(), RabbitMqClientError>
+    where
+        T: Serialize + Send + Sync + std::fmt::Debug;
+
+    async fn delete_parameter(
+        &self,
+        component: String,
+        vhost: String,
+        name: String,
+    ) -> Result<(), RabbitMqClientError>;
+}
+
+#[async_trait]
+impl ParameterApi for RabbitMqClient {
+    #[tracing::instrument(skip(self))]
+    async fn list_parameters<T>(
+        &self,
+        component: Option<String>,
+    ) -> Result<Vec<RabbitMqParameter<T>>, RabbitMqClientError>
+    where
+        T: DeserializeOwned,
+    {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/parameters/{}", component.unwrap_or_default()),
+            )
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_vhost_parameters<T>(
+        &self,
+        component: String,
+        vhost: String,
+    ) -> Result<Vec<RabbitMqParameter<T>>, RabbitMqClientError>
+    where
+        T: DeserializeOwned,
+    {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/parameters/{}/{}", component, vhost),
+            )
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_parameter<T>(
+        &self,
+        component: String,
+        vhost: String,
+        name: String,
+    ) -> Result<RabbitMqParameter<T>, RabbitMqClientError>
+    where
+        T: DeserializeOwned,
+    {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/parameters/{}/{}/{}", component, vhost, name),
+            )
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn create_parameter<T>(
+        &self,
+        component: String,
+        vhost: String,
+        name: String,
+        request: RabbitMqParameterRequest<T>,
+    ) -> Result<(), RabbitMqClientError>
+    where
+        T: Serialize + Send + Sync + std::fmt::Debug,
+    {
+        match self
+            .get_parameter::<serde_json::Value>(component.clone(), vhost.clone(), name.clone())
+            .await
+        {
+            Ok(_) => Err(RabbitMqClientError::AlreadyExists(format!(
+                "{} {} parameter",
+                name, component
+            ))),
+            Err(e) => match e {
+                RabbitMqClientError::NotFound(_) => {
+                    self.update_parameter(component, vhost, name, request).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn update_parameter<T>(
+        &self,
+        component: String,
+        vhost: String,
+        name: String,
+        request: RabbitMqParameterRequest<T>,
+    ) -> Result<(), RabbitMqClientError>
+    where
+        T: Serialize + Send + Sync + std::fmt::Debug,
+    {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::PUT,
+                api_url!(self, "/api/parameters/{}/{}/{}", component, vhost, name),
+            )
+            .json(&request)
+            .send()
+            .await?;
+
+        handle_empty_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_parameter(
+        &self,
+        component: String,
+        vhost: String,
+        name: String,
+    ) -> Result<(), RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::DELETE,
+                api_url!(self, "/api/parameters/{}/{}/{}", component, vhost, name),
+            )
+            .send()
+            .await?;
+
+        handle_empty_response(response).await
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RabbitMqParameter<T = serde_json::Value> {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vhost: Option<String>,
+    pub component: String,
+    pub value: T,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RabbitMqParameterRequest<T = serde_json::Value> {
+    pub value: T,
+}
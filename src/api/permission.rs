@@ -1,8 +1,9 @@
 use crate::api::_generic::{handle_empty_response, handle_response};
+use crate::api::_url::api_url;
 use crate::errors::RabbitMqClientError;
 use crate::RabbitMqClient;
 use async_trait::async_trait;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 #[async_trait]
 pub trait PermissionApi {
@@ -14,6 +15,15 @@ pub trait PermissionApi {
         user: String,
     ) -> Result<RabbitMqPermission, RabbitMqClientError>;
 
+    async fn set_permission(
+        &self,
+        vhost: String,
+        user: String,
+        configure: String,
+        write: String,
+        read: String,
+    ) -> Result<(), RabbitMqClientError>;
+
     async fn delete_permission(
         &self,
         vhost: String,
@@ -28,7 +38,16 @@ pub trait PermissionApi {
         &self,
         vhost: String,
         user: String,
-    ) -> Result<RabbitMqTopicPermission, RabbitMqClientError>;
+    ) -> Result<Vec<RabbitMqTopicPermission>, RabbitMqClientError>;
+
+    async fn set_topic_permission(
+        &self,
+        vhost: String,
+        user: String,
+        exchange: String,
+        write: String,
+        read: String,
+    ) -> Result<(), RabbitMqClientError>;
 
     async fn delete_topic_permission(
         &self,
@@ -42,10 +61,7 @@ impl PermissionApi for RabbitMqClient {
     async fn list_permissions(&self) -> Result<Vec<RabbitMqPermission>, RabbitMqClientError> {
         let response = self
             .client
-            .request(
-                reqwest::Method::GET,
-                format!("{}/api/permissions", self.api_url),
-            )
+            .request(reqwest::Method::GET, api_url!(self, "/api/permissions"))
             .send()
             .await?;
 
@@ -61,7 +77,7 @@ impl PermissionApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::GET,
-                format!("{}/api/permissions/{}/{}", self.api_url, vhost, user),
+                api_url!(self, "/api/permissions/{}/{}", vhost, user),
             )
             .send()
             .await?;
@@ -69,6 +85,31 @@ impl PermissionApi for RabbitMqClient {
         handle_response(response).await
     }
 
+    async fn set_permission(
+        &self,
+        vhost: String,
+        user: String,
+        configure: String,
+        write: String,
+        read: String,
+    ) -> Result<(), RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::PUT,
+                api_url!(self, "/api/permissions/{}/{}", vhost, user),
+            )
+            .json(&RabbitMqPermissionRequest {
+                configure,
+                write,
+                read,
+            })
+            .send()
+            .await?;
+
+        handle_empty_response(response).await
+    }
+
     async fn delete_permission(
         &self,
         vhost: String,
@@ -78,7 +119,7 @@ impl PermissionApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::DELETE,
-                format!("{}/api/permissions/{}/{}", self.api_url, vhost, user),
+                api_url!(self, "/api/permissions/{}/{}", vhost, user),
             )
             .send()
             .await?;
@@ -93,7 +134,7 @@ impl PermissionApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::GET,
-                format!("{}/api/topic-permissions", self.api_url),
+                api_url!(self, "/api/topic-permissions"),
             )
             .send()
             .await?;
@@ -105,12 +146,12 @@ impl PermissionApi for RabbitMqClient {
         &self,
         vhost: String,
         user: String,
-    ) -> Result<RabbitMqTopicPermission, RabbitMqClientError> {
+    ) -> Result<Vec<RabbitMqTopicPermission>, RabbitMqClientError> {
         let response = self
             .client
             .request(
                 reqwest::Method::GET,
-                format!("{}/api/topic-permissions/{}/{}", self.api_url, vhost, user),
+                api_url!(self, "/api/topic-permissions/{}/{}", vhost, user),
             )
             .send()
             .await?;
@@ -118,6 +159,31 @@ impl PermissionApi for RabbitMqClient {
         handle_response(response).await
     }
 
+    async fn set_topic_permission(
+        &self,
+        vhost: String,
+        user: String,
+        exchange: String,
+        write: String,
+        read: String,
+    ) -> Result<(), RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::PUT,
+                api_url!(self, "/api/topic-permissions/{}/{}", vhost, user),
+            )
+            .json(&RabbitMqTopicPermissionRequest {
+                exchange,
+                write,
+                read,
+            })
+            .send()
+            .await?;
+
+        handle_empty_response(response).await
+    }
+
     async fn delete_topic_permission(
         &self,
         vhost: String,
@@ -127,7 +193,7 @@ impl PermissionApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::DELETE,
-                format!("{}/api/topic-permissions/{}/{}", self.api_url, vhost, user),
+                api_url!(self, "/api/topic-permissions/{}/{}", vhost, user),
             )
             .send()
             .await?;
@@ -136,7 +202,7 @@ impl PermissionApi for RabbitMqClient {
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct RabbitMqPermission {
     pub vhost: String,
     pub user: String,
@@ -145,5 +211,25 @@ pub struct RabbitMqPermission {
     pub read: String,
 }
 
-#[derive(Debug, Deserialize)]
-pub struct RabbitMqTopicPermission {}
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RabbitMqTopicPermission {
+    pub vhost: String,
+    pub user: String,
+    pub exchange: String,
+    pub write: String,
+    pub read: String,
+}
+
+#[derive(Debug, Serialize)]
+struct RabbitMqPermissionRequest {
+    configure: String,
+    write: String,
+    read: String,
+}
+
+#[derive(Debug, Serialize)]
+struct RabbitMqTopicPermissionRequest {
+    exchange: String,
+    write: String,
+    read: String,
+}
@@ -1,4 +1,5 @@
 use crate::api::_generic::{handle_empty_response, handle_response};
+use crate::api::_url::api_url;
 use crate::errors::RabbitMqClientError;
 use crate::RabbitMqClient;
 use async_trait::async_trait;
@@ -46,11 +47,7 @@ impl PolicyApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::GET,
-                format!(
-                    "{}/api/policies/{}",
-                    self.api_url,
-                    vhost.unwrap_or_default()
-                ),
+                api_url!(self, "/api/policies/{}", vhost.unwrap_or_default()),
             )
             .send()
             .await?;
@@ -67,7 +64,7 @@ impl PolicyApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::GET,
-                format!("{}/api/policies/{}/{}", self.api_url, vhost, policy),
+                api_url!(self, "/api/policies/{}/{}", vhost, policy),
             )
             .send()
             .await?;
@@ -102,7 +99,7 @@ impl PolicyApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::PUT,
-                format!("{}/api/policies/{}/{}", self.api_url, vhost, policy),
+                api_url!(self, "/api/policies/{}/{}", vhost, policy),
             )
             .json(&request)
             .send()
@@ -120,7 +117,7 @@ impl PolicyApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::DELETE,
-                format!("{}/api/policies/{}/{}", self.api_url, vhost, policy),
+                api_url!(self, "/api/policies/{}/{}", vhost, policy),
             )
             .send()
             .await?;
@@ -129,9 +126,16 @@ impl PolicyApi for RabbitMqClient {
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct RabbitMqPolicy {
     pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vhost: Option<String>,
+    pub pattern: String,
+    #[serde(rename = "apply-to")]
+    pub apply_to: String,
+    pub definition: HashMap<String, RabbitMqPolicyDefinitionValue>,
+    pub priority: i64,
 }
 
 #[derive(Debug, Serialize)]
@@ -145,8 +149,13 @@ pub struct RabbitMqPolicyRequest {
     pub apply_to: Option<String>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
 pub enum RabbitMqPolicyDefinitionValue {
     String(String),
     Integer(i64),
+    Float(f64),
+    Boolean(bool),
+    List(Vec<RabbitMqPolicyDefinitionValue>),
+    Object(HashMap<String, RabbitMqPolicyDefinitionValue>),
 }
@@ -1,12 +1,14 @@
 use crate::api::_generic::{handle_empty_response, handle_response};
+use crate::api::_url::api_url;
 use crate::api::binding::RabbitMqBinding;
 use crate::api::options::pagination::RabbitMqPaginationRequest;
 use crate::api::RabbitMqPaginatedResponse;
 use crate::errors::RabbitMqClientError;
 use crate::RabbitMqClient;
 use async_trait::async_trait;
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 use std::collections::HashMap;
 
 use super::options::RabbitMqRequestOptions;
@@ -55,6 +57,28 @@ pub trait QueueApi {
         queue: String,
         action: RabbitMqQueueAction,
     ) -> Result<(), RabbitMqClientError>;
+
+    async fn add_quorum_queue_replica(
+        &self,
+        vhost: String,
+        queue: String,
+        node: String,
+    ) -> Result<(), RabbitMqClientError>;
+
+    async fn delete_quorum_queue_replica(
+        &self,
+        vhost: String,
+        queue: String,
+        node: String,
+    ) -> Result<(), RabbitMqClientError>;
+
+    async fn grow_quorum_queues_on_node(
+        &self,
+        node: String,
+        request: RabbitMqQuorumQueueGrowRequest,
+    ) -> Result<(), RabbitMqClientError>;
+
+    async fn shrink_quorum_queues_on_node(&self, node: String) -> Result<(), RabbitMqClientError>;
 }
 
 #[async_trait]
@@ -72,7 +96,7 @@ impl QueueApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::GET,
-                format!("{}/api/queues/{}", self.api_url, vhost.unwrap_or_default()),
+                api_url!(self, "/api/queues/{}", vhost.unwrap_or_default()),
             )
             .query(&pagination)
             .query(&options.sorting)
@@ -93,7 +117,7 @@ impl QueueApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::GET,
-                format!("{}/api/queues/{}/{}", self.api_url, vhost, name),
+                api_url!(self, "/api/queues/{}/{}", vhost, name),
             )
             .send()
             .await?;
@@ -111,7 +135,7 @@ impl QueueApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::GET,
-                format!("{}/api/queues/{}/{}/bindings", self.api_url, vhost, name),
+                api_url!(self, "/api/queues/{}/{}/bindings", vhost, name),
             )
             .send()
             .await?;
@@ -149,7 +173,7 @@ impl QueueApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::PUT,
-                format!("{}/api/queues/{}/{}", self.api_url, vhost, queue),
+                api_url!(self, "/api/queues/{}/{}", vhost, queue),
             )
             .json(&request)
             .send()
@@ -164,7 +188,7 @@ impl QueueApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::DELETE,
-                format!("{}/api/queues/{}/{}", self.api_url, vhost, name),
+                api_url!(self, "/api/queues/{}/{}", vhost, name),
             )
             .send()
             .await?;
@@ -178,7 +202,7 @@ impl QueueApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::DELETE,
-                format!("{}/api/queues/{}/{}/contents", self.api_url, vhost, name),
+                api_url!(self, "/api/queues/{}/{}/contents", vhost, name),
             )
             .send()
             .await?;
@@ -197,7 +221,7 @@ impl QueueApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::POST,
-                format!("{}/api/queues/{}/{}/actions", self.api_url, vhost, queue),
+                api_url!(self, "/api/queues/{}/{}/actions", vhost, queue),
             )
             .json(&RabbitMqQueueActionRequest { action })
             .send()
@@ -205,6 +229,84 @@ impl QueueApi for RabbitMqClient {
 
         handle_empty_response(response).await
     }
+
+    #[tracing::instrument(skip(self))]
+    async fn add_quorum_queue_replica(
+        &self,
+        vhost: String,
+        queue: String,
+        node: String,
+    ) -> Result<(), RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::POST,
+                api_url!(self, "/api/queues/quorum/{}/{}/replicas/add", vhost, queue),
+            )
+            .json(&RabbitMqQuorumQueueReplicaRequest { node })
+            .send()
+            .await?;
+
+        handle_empty_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_quorum_queue_replica(
+        &self,
+        vhost: String,
+        queue: String,
+        node: String,
+    ) -> Result<(), RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::DELETE,
+                api_url!(
+                    self,
+                    "/api/queues/quorum/{}/{}/replicas/delete",
+                    vhost,
+                    queue
+                ),
+            )
+            .json(&RabbitMqQuorumQueueReplicaRequest { node })
+            .send()
+            .await?;
+
+        handle_empty_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn grow_quorum_queues_on_node(
+        &self,
+        node: String,
+        request: RabbitMqQuorumQueueGrowRequest,
+    ) -> Result<(), RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::POST,
+                api_url!(self, "/api/queues/quorum/replicas/on/{}/grow", node),
+            )
+            .json(&request)
+            .send()
+            .await?;
+
+        handle_empty_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn shrink_quorum_queues_on_node(&self, node: String) -> Result<(), RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::DELETE,
+                api_url!(self, "/api/queues/quorum/replicas/on/{}/shrink", node),
+            )
+            .send()
+            .await?;
+
+        handle_empty_response(response).await
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -227,13 +329,30 @@ pub struct RabbitMqQueue {
     pub messages_unacknowledged: Option<i64>,
     pub garbage_collection: Option<RabbitMqQueueGarbageCollection>,
     pub message_stats: Option<RabbitMqQueueMessageStats>,
+    pub leader: Option<String>,
+    #[serde(default)]
+    pub members: Vec<String>,
+    #[serde(default)]
+    pub online: Vec<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum RabbitMqArgument {
     String(String),
+    #[serde(serialize_with = "serialize_decimal_argument")]
     Decimal(Decimal),
+    Boolean(bool),
+}
+
+fn serialize_decimal_argument<S>(value: &Decimal, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match value.to_i64().filter(|_| value.is_integer()) {
+        Some(integer) => serializer.serialize_i64(integer),
+        None => Serialize::serialize(value, serializer),
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -267,12 +386,12 @@ pub struct RabbitMqQueueGarbageCollection {
     pub minor_gcs: i64,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Default, Serialize)]
 pub struct RabbitMqQueueRequest {
     pub auto_delete: bool,
     pub durable: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub arguments: Option<HashMap<String, String>>,
+    pub arguments: Option<HashMap<String, RabbitMqArgument>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub node: Option<String>,
 }
@@ -288,3 +407,22 @@ pub enum RabbitMqQueueAction {
     Sync,
     CancelSync,
 }
+
+#[derive(Debug, Serialize)]
+struct RabbitMqQuorumQueueReplicaRequest {
+    node: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RabbitMqQuorumQueueGrowRequest {
+    pub vhost_pattern: String,
+    pub queue_pattern: String,
+    pub strategy: RabbitMqQuorumQueueGrowStrategy,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RabbitMqQuorumQueueGrowStrategy {
+    All,
+    Even,
+}
@@ -0,0 +1,130 @@
+use crate::api::_generic::handle_empty_response;
+use crate::api::_url::api_url;
+use crate::api::node::NodeApi;
+use crate::api::options::pagination::RabbitMqPagination;
+use crate::api::options::RabbitMqRequestOptions;
+use crate::api::queue::QueueApi;
+use crate::errors::RabbitMqClientError;
+use crate::RabbitMqClient;
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+const LEADER_DISTRIBUTION_PAGE_SIZE: u32 = 500;
+
+#[async_trait]
+pub trait RebalanceApi {
+    async fn rebalance_queues(&self) -> Result<(), RabbitMqClientError>;
+
+    async fn rebalance_queues_with_report(
+        &self,
+    ) -> Result<RabbitMqRebalanceReport, RabbitMqClientError>;
+
+    async fn get_queue_leader_distribution(
+        &self,
+    ) -> Result<Vec<RabbitMqNodeQueueLeaders>, RabbitMqClientError>;
+}
+
+#[async_trait]
+impl RebalanceApi for RabbitMqClient {
+    #[tracing::instrument(skip(self))]
+    async fn rebalance_queues(&self) -> Result<(), RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::POST,
+                api_url!(self, "/api/rebalance/queues"),
+            )
+            .send()
+            .await?;
+
+        handle_empty_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn rebalance_queues_with_report(
+        &self,
+    ) -> Result<RabbitMqRebalanceReport, RabbitMqClientError> {
+        let before = self.get_queue_leader_distribution().await?;
+
+        self.rebalance_queues().await?;
+
+        let after = self.get_queue_leader_distribution().await?;
+
+        Ok(RabbitMqRebalanceReport { before, after })
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_queue_leader_distribution(
+        &self,
+    ) -> Result<Vec<RabbitMqNodeQueueLeaders>, RabbitMqClientError> {
+        let mut distribution: Vec<RabbitMqNodeQueueLeaders> = self
+            .list_nodes()
+            .await?
+            .into_iter()
+            .map(|node| RabbitMqNodeQueueLeaders {
+                node: node.name,
+                running: node.running,
+                total: 0,
+                by_type: HashMap::new(),
+            })
+            .collect();
+
+        let mut page = 1;
+        loop {
+            let queues = self
+                .list_queues(
+                    None,
+                    Some(RabbitMqRequestOptions {
+                        pagination: Some(RabbitMqPagination {
+                            page,
+                            page_size: Some(LEADER_DISTRIBUTION_PAGE_SIZE),
+                            filter: None,
+                        }),
+                        disable_stats: true,
+                        ..Default::default()
+                    }),
+                )
+                .await?;
+
+            for queue in queues.items {
+                let index = match distribution.iter().position(|n| n.node == queue.node) {
+                    Some(index) => index,
+                    None => {
+                        distribution.push(RabbitMqNodeQueueLeaders {
+                            node: queue.node.clone(),
+                            running: false,
+                            total: 0,
+                            by_type: HashMap::new(),
+                        });
+                        distribution.len() - 1
+                    }
+                };
+
+                let leaders = &mut distribution[index];
+                leaders.total += 1;
+                *leaders.by_type.entry(queue.kind).or_default() += 1;
+            }
+
+            if page >= queues.page_count {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(distribution)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RabbitMqRebalanceReport {
+    pub before: Vec<RabbitMqNodeQueueLeaders>,
+    pub after: Vec<RabbitMqNodeQueueLeaders>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RabbitMqNodeQueueLeaders {
+    pub node: String,
+    pub running: bool,
+    pub total: u32,
+    pub by_type: HashMap<String, u32>,
+}
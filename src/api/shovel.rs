@@ -0,0 +1,370 @@
+use crate::api::_generic::{deserialize_uris, handle_empty_response, handle_response};
+use crate::api::_url::api_url;
+use crate::api::parameter::{ParameterApi, RabbitMqParameter, RabbitMqParameterRequest};
+use crate::errors::RabbitMqClientError;
+use crate::RabbitMqClient;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+const SHOVEL_COMPONENT: &str = "shovel";
+
+#[async_trait]
+pub trait ShovelApi {
+    async fn list_shovels(
+        &self,
+        vhost: Option<String>,
+    ) -> Result<Vec<RabbitMqParameter<RabbitMqShovelDefinition>>, RabbitMqClientError>;
+
+    async fn get_shovel(
+        &self,
+        vhost: String,
+        name: String,
+    ) -> Result<RabbitMqParameter<RabbitMqShovelDefinition>, RabbitMqClientError>;
+
+    async fn create_shovel(
+        &self,
+        vhost: String,
+        name: String,
+        definition: RabbitMqShovelDefinition,
+    ) -> Result<(), RabbitMqClientError>;
+
+    async fn update_shovel(
+        &self,
+        vhost: String,
+        name: String,
+        definition: RabbitMqShovelDefinition,
+    ) -> Result<(), RabbitMqClientError>;
+
+    async fn delete_shovel(&self, vhost: String, name: String) -> Result<(), RabbitMqClientError>;
+
+    async fn list_shovel_status(
+        &self,
+        vhost: Option<String>,
+    ) -> Result<Vec<RabbitMqShovelStatus>, RabbitMqClientError>;
+
+    async fn restart_shovel(&self, vhost: String, name: String) -> Result<(), RabbitMqClientError>;
+}
+
+#[async_trait]
+impl ShovelApi for RabbitMqClient {
+    #[tracing::instrument(skip(self))]
+    async fn list_shovels(
+        &self,
+        vhost: Option<String>,
+    ) -> Result<Vec<RabbitMqParameter<RabbitMqShovelDefinition>>, RabbitMqClientError> {
+        match vhost {
+            None => {
+                self.list_parameters(Some(SHOVEL_COMPONENT.to_string()))
+                    .await
+            }
+            Some(vhost) => {
+                self.list_vhost_parameters(SHOVEL_COMPONENT.to_string(), vhost)
+                    .await
+            }
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_shovel(
+        &self,
+        vhost: String,
+        name: String,
+    ) -> Result<RabbitMqParameter<RabbitMqShovelDefinition>, RabbitMqClientError> {
+        self.get_parameter(SHOVEL_COMPONENT.to_string(), vhost, name)
+            .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn create_shovel(
+        &self,
+        vhost: String,
+        name: String,
+        definition: RabbitMqShovelDefinition,
+    ) -> Result<(), RabbitMqClientError> {
+        definition.validate()?;
+
+        self.create_parameter(
+            SHOVEL_COMPONENT.to_string(),
+            vhost,
+            name,
+            RabbitMqParameterRequest { value: definition },
+        )
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn update_shovel(
+        &self,
+        vhost: String,
+        name: String,
+        definition: RabbitMqShovelDefinition,
+    ) -> Result<(), RabbitMqClientError> {
+        definition.validate()?;
+
+        self.update_parameter(
+            SHOVEL_COMPONENT.to_string(),
+            vhost,
+            name,
+            RabbitMqParameterRequest { value: definition },
+        )
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_shovel(&self, vhost: String, name: String) -> Result<(), RabbitMqClientError> {
+        self.delete_parameter(SHOVEL_COMPONENT.to_string(), vhost, name)
+            .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_shovel_status(
+        &self,
+        vhost: Option<String>,
+    ) -> Result<Vec<RabbitMqShovelStatus>, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/shovels/{}", vhost.unwrap_or_default()),
+            )
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn restart_shovel(&self, vhost: String, name: String) -> Result<(), RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::DELETE,
+                api_url!(self, "/api/shovels/vhost/{}/{}/restart", vhost, name),
+            )
+            .send()
+            .await?;
+
+        handle_empty_response(response).await
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct RabbitMqShovelDefinition {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_protocol: Option<RabbitMqShovelProtocol>,
+    #[serde(deserialize_with = "deserialize_uris")]
+    pub src_uri: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_queue: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_exchange: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_exchange_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_prefetch_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_delete_after: Option<RabbitMqShovelDeleteAfter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_predeclared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_consumer_args: Option<HashMap<String, serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dest_protocol: Option<RabbitMqShovelProtocol>,
+    #[serde(deserialize_with = "deserialize_uris")]
+    pub dest_uri: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dest_queue: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dest_exchange: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dest_exchange_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dest_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dest_predeclared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dest_add_forward_headers: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dest_add_timestamp_header: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dest_publish_properties: Option<HashMap<String, serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dest_properties: Option<HashMap<String, serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dest_application_properties: Option<HashMap<String, serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dest_message_annotations: Option<HashMap<String, serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ack_mode: Option<RabbitMqShovelAckMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reconnect_delay: Option<u32>,
+}
+
+impl RabbitMqShovelDefinition {
+    pub fn validate(&self) -> Result<(), RabbitMqClientError> {
+        if self.src_uri.is_empty() {
+            return Err(invalid_shovel("src-uri must not be empty"));
+        }
+
+        if self.dest_uri.is_empty() {
+            return Err(invalid_shovel("dest-uri must not be empty"));
+        }
+
+        match self.src_protocol.clone().unwrap_or_default() {
+            RabbitMqShovelProtocol::Amqp091 => {
+                match (&self.src_queue, &self.src_exchange) {
+                    (Some(_), Some(_)) => {
+                        return Err(invalid_shovel(
+                            "src-queue and src-exchange are mutually exclusive",
+                        ))
+                    }
+                    (None, None) => {
+                        return Err(invalid_shovel(
+                            "either src-queue or src-exchange must be set",
+                        ))
+                    }
+                    _ => {}
+                }
+
+                if self.src_exchange_key.is_some() && self.src_exchange.is_none() {
+                    return Err(invalid_shovel("src-exchange-key requires src-exchange"));
+                }
+
+                if self.src_address.is_some() {
+                    return Err(invalid_shovel(
+                        "src-address is only supported by the amqp10 protocol",
+                    ));
+                }
+            }
+            RabbitMqShovelProtocol::Amqp10 => {
+                if self.src_address.is_none() {
+                    return Err(invalid_shovel("src-address must be set"));
+                }
+
+                if self.src_queue.is_some()
+                    || self.src_exchange.is_some()
+                    || self.src_exchange_key.is_some()
+                {
+                    return Err(invalid_shovel(
+                        "src-queue, src-exchange and src-exchange-key are only supported by the amqp091 protocol",
+                    ));
+                }
+            }
+        }
+
+        match self.dest_protocol.clone().unwrap_or_default() {
+            RabbitMqShovelProtocol::Amqp091 => {
+                if self.dest_queue.is_some() && self.dest_exchange.is_some() {
+                    return Err(invalid_shovel(
+                        "dest-queue and dest-exchange are mutually exclusive",
+                    ));
+                }
+
+                if self.dest_exchange_key.is_some() && self.dest_exchange.is_none() {
+                    return Err(invalid_shovel("dest-exchange-key requires dest-exchange"));
+                }
+
+                if self.dest_address.is_some() {
+                    return Err(invalid_shovel(
+                        "dest-address is only supported by the amqp10 protocol",
+                    ));
+                }
+            }
+            RabbitMqShovelProtocol::Amqp10 => {
+                if self.dest_address.is_none() {
+                    return Err(invalid_shovel("dest-address must be set"));
+                }
+
+                if self.dest_queue.is_some()
+                    || self.dest_exchange.is_some()
+                    || self.dest_exchange_key.is_some()
+                {
+                    return Err(invalid_shovel(
+                        "dest-queue, dest-exchange and dest-exchange-key are only supported by the amqp091 protocol",
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn invalid_shovel(reason: &str) -> RabbitMqClientError {
+    RabbitMqClientError::InvalidRequest(format!("invalid shovel definition: {}", reason))
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub enum RabbitMqShovelProtocol {
+    #[default]
+    #[serde(rename = "amqp091")]
+    Amqp091,
+    #[serde(rename = "amqp10")]
+    Amqp10,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RabbitMqShovelAckMode {
+    OnConfirm,
+    OnPublish,
+    NoAck,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum RabbitMqShovelDeleteAfter {
+    Mode(RabbitMqShovelDeleteAfterMode),
+    Messages(u64),
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RabbitMqShovelDeleteAfterMode {
+    Never,
+    QueueLength,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqShovelStatus {
+    pub name: String,
+    pub vhost: Option<String>,
+    pub node: String,
+    #[serde(rename = "type")]
+    pub kind: RabbitMqShovelType,
+    pub state: RabbitMqShovelState,
+    pub reason: Option<String>,
+    pub timestamp: Option<String>,
+    pub blocked_status: Option<String>,
+    pub src_protocol: Option<RabbitMqShovelProtocol>,
+    pub src_uri: Option<String>,
+    pub src_queue: Option<String>,
+    pub src_exchange: Option<String>,
+    pub src_exchange_key: Option<String>,
+    pub src_address: Option<String>,
+    pub dest_protocol: Option<RabbitMqShovelProtocol>,
+    pub dest_uri: Option<String>,
+    pub dest_queue: Option<String>,
+    pub dest_exchange: Option<String>,
+    pub dest_exchange_key: Option<String>,
+    pub dest_address: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RabbitMqShovelType {
+    Static,
+    Dynamic,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RabbitMqShovelState {
+    Starting,
+    Running,
+    Terminated,
+}
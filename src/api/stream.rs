@@ -0,0 +1,230 @@
+use crate::api::_generic::handle_response;
+use crate::api::_url::api_url;
+use crate::api::channel::RabbitMqQueueReference;
+use crate::api::consumer::RabbitMqConsumerActivityStatus;
+use crate::errors::RabbitMqClientError;
+use crate::RabbitMqClient;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[async_trait]
+pub trait StreamApi {
+    async fn list_stream_connections(
+        &self,
+        vhost: Option<String>,
+    ) -> Result<Vec<RabbitMqStreamConnection>, RabbitMqClientError>;
+
+    async fn get_stream_connection(
+        &self,
+        vhost: String,
+        name: String,
+    ) -> Result<RabbitMqStreamConnection, RabbitMqClientError>;
+
+    async fn list_stream_connection_publishers(
+        &self,
+        vhost: String,
+        name: String,
+    ) -> Result<Vec<RabbitMqStreamPublisher>, RabbitMqClientError>;
+
+    async fn list_stream_connection_consumers(
+        &self,
+        vhost: String,
+        name: String,
+    ) -> Result<Vec<RabbitMqStreamConsumer>, RabbitMqClientError>;
+
+    async fn list_stream_publishers(
+        &self,
+        vhost: Option<String>,
+    ) -> Result<Vec<RabbitMqStreamPublisher>, RabbitMqClientError>;
+
+    async fn list_stream_consumers(
+        &self,
+        vhost: Option<String>,
+    ) -> Result<Vec<RabbitMqStreamConsumer>, RabbitMqClientError>;
+}
+
+#[async_trait]
+impl StreamApi for RabbitMqClient {
+    #[tracing::instrument(skip(self))]
+    async fn list_stream_connections(
+        &self,
+        vhost: Option<String>,
+    ) -> Result<Vec<RabbitMqStreamConnection>, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(
+                    self,
+                    "/api/stream/connections/{}",
+                    vhost.unwrap_or_default()
+                ),
+            )
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_stream_connection(
+        &self,
+        vhost: String,
+        name: String,
+    ) -> Result<RabbitMqStreamConnection, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/stream/connections/{}/{}", vhost, name),
+            )
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_stream_connection_publishers(
+        &self,
+        vhost: String,
+        name: String,
+    ) -> Result<Vec<RabbitMqStreamPublisher>, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(
+                    self,
+                    "/api/stream/connections/{}/{}/publishers",
+                    vhost,
+                    name
+                ),
+            )
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_stream_connection_consumers(
+        &self,
+        vhost: String,
+        name: String,
+    ) -> Result<Vec<RabbitMqStreamConsumer>, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/stream/connections/{}/{}/consumers", vhost, name),
+            )
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_stream_publishers(
+        &self,
+        vhost: Option<String>,
+    ) -> Result<Vec<RabbitMqStreamPublisher>, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/stream/publishers/{}", vhost.unwrap_or_default()),
+            )
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_stream_consumers(
+        &self,
+        vhost: Option<String>,
+    ) -> Result<Vec<RabbitMqStreamConsumer>, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/stream/consumers/{}", vhost.unwrap_or_default()),
+            )
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqStreamConnection {
+    pub name: String,
+    pub node: String,
+    pub vhost: String,
+    pub user: String,
+    pub auth_mechanism: Option<String>,
+    pub host: Option<String>,
+    pub port: Option<i64>,
+    pub peer_host: Option<String>,
+    pub peer_port: Option<i64>,
+    #[serde(default, with = "chrono::serde::ts_milliseconds_option")]
+    pub connected_at: Option<DateTime<Utc>>,
+    pub heartbeat: Option<i64>,
+    pub frame_max: Option<i64>,
+    #[serde(default)]
+    pub client_properties: HashMap<String, serde_json::Value>,
+    pub recv_cnt: Option<i64>,
+    pub recv_oct: Option<i64>,
+    pub send_cnt: Option<i64>,
+    pub send_oct: Option<i64>,
+    pub send_pend: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqStreamConnectionDetails {
+    pub name: String,
+    pub node: Option<String>,
+    pub user: Option<String>,
+    pub peer_host: Option<String>,
+    pub peer_port: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqStreamPublisher {
+    pub publisher_id: i64,
+    #[serde(default)]
+    pub reference: String,
+    pub queue: RabbitMqQueueReference,
+    pub connection_details: RabbitMqStreamConnectionDetails,
+    #[serde(default)]
+    pub published: i64,
+    #[serde(default)]
+    pub confirmed: i64,
+    #[serde(default)]
+    pub errored: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqStreamConsumer {
+    pub subscription_id: i64,
+    pub queue: RabbitMqQueueReference,
+    pub connection_details: RabbitMqStreamConnectionDetails,
+    #[serde(default)]
+    pub offset: i64,
+    #[serde(default)]
+    pub offset_lag: i64,
+    #[serde(default)]
+    pub credits: i64,
+    #[serde(default)]
+    pub consumed: i64,
+    pub active: Option<bool>,
+    pub activity_status: Option<RabbitMqConsumerActivityStatus>,
+    #[serde(default)]
+    pub properties: HashMap<String, serde_json::Value>,
+}
@@ -0,0 +1,234 @@
+use crate::api::binding::{BindingApi, RabbitMqBindingDestinationType, RabbitMqBindingRequest};
+use crate::api::exchange::{ExchangeApi, RabbitMqExchangeRequest};
+use crate::api::queue::{QueueApi, RabbitMqArgument, RabbitMqQueueRequest};
+use crate::errors::RabbitMqClientError;
+use crate::RabbitMqClient;
+use async_trait::async_trait;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+
+const SUPER_STREAM_ARGUMENT: &str = "x-super-stream";
+const PARTITION_ORDER_ARGUMENT: &str = "x-stream-partition-order";
+
+#[async_trait]
+pub trait SuperStreamApi {
+    async fn get_super_stream(
+        &self,
+        vhost: String,
+        name: String,
+    ) -> Result<RabbitMqSuperStream, RabbitMqClientError>;
+
+    async fn create_super_stream(
+        &self,
+        vhost: String,
+        name: String,
+        request: RabbitMqSuperStreamRequest,
+    ) -> Result<RabbitMqSuperStream, RabbitMqClientError>;
+
+    async fn delete_super_stream(
+        &self,
+        vhost: String,
+        name: String,
+    ) -> Result<(), RabbitMqClientError>;
+}
+
+#[async_trait]
+impl SuperStreamApi for RabbitMqClient {
+    #[tracing::instrument(skip(self))]
+    async fn get_super_stream(
+        &self,
+        vhost: String,
+        name: String,
+    ) -> Result<RabbitMqSuperStream, RabbitMqClientError> {
+        let exchange = self.get_exchange(vhost.clone(), name.clone()).await?;
+
+        if exchange.arguments.get(SUPER_STREAM_ARGUMENT) != Some(&RabbitMqArgument::Boolean(true)) {
+            return Err(RabbitMqClientError::InvalidRequest(format!(
+                "{} is not a super stream",
+                name
+            )));
+        }
+
+        let mut partitions: Vec<RabbitMqSuperStreamPartition> = self
+            .list_source_bindings(vhost.clone(), name.clone())
+            .await?
+            .into_iter()
+            .filter(|b| matches!(b.destination_type, RabbitMqBindingDestinationType::Queue))
+            .map(|b| RabbitMqSuperStreamPartition {
+                order: match b.arguments.get(PARTITION_ORDER_ARGUMENT) {
+                    Some(RabbitMqArgument::Decimal(order)) => order.to_i64(),
+                    _ => None,
+                },
+                stream: b.destination,
+                binding_key: b.routing_key,
+            })
+            .collect();
+
+        partitions.sort_by_key(|p| (p.order.is_none(), p.order));
+
+        Ok(RabbitMqSuperStream {
+            name,
+            vhost,
+            partitions,
+        })
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn create_super_stream(
+        &self,
+        vhost: String,
+        name: String,
+        request: RabbitMqSuperStreamRequest,
+    ) -> Result<RabbitMqSuperStream, RabbitMqClientError> {
+        let binding_keys = request.partitions.binding_keys();
+
+        if binding_keys.is_empty() {
+            return Err(RabbitMqClientError::InvalidRequest(format!(
+                "{} super stream needs at least one partition",
+                name
+            )));
+        }
+
+        self.create_exchange(
+            vhost.clone(),
+            name.clone(),
+            RabbitMqExchangeRequest {
+                kind: "direct".to_string(),
+                auto_delete: false,
+                durable: true,
+                internal: false,
+                arguments: Some(HashMap::from([(
+                    SUPER_STREAM_ARGUMENT.to_string(),
+                    RabbitMqArgument::Boolean(true),
+                )])),
+            },
+        )
+        .await?;
+
+        let mut streams: Vec<String> = Vec::new();
+        let declared = declare_super_stream_partitions(
+            self,
+            &vhost,
+            &name,
+            binding_keys,
+            &request,
+            &mut streams,
+        )
+        .await;
+
+        if let Err(e) = declared {
+            for stream in streams.into_iter().rev() {
+                let _ = self.delete_queue(vhost.clone(), stream).await;
+            }
+            let _ = self.delete_exchange(vhost.clone(), name.clone()).await;
+
+            return Err(e);
+        }
+
+        self.get_super_stream(vhost, name).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_super_stream(
+        &self,
+        vhost: String,
+        name: String,
+    ) -> Result<(), RabbitMqClientError> {
+        let super_stream = self.get_super_stream(vhost.clone(), name.clone()).await?;
+
+        for partition in super_stream.partitions {
+            self.delete_queue(vhost.clone(), partition.stream).await?;
+        }
+
+        self.delete_exchange(vhost, name).await
+    }
+}
+
+async fn declare_super_stream_partitions(
+    client: &RabbitMqClient,
+    vhost: &str,
+    name: &str,
+    binding_keys: Vec<String>,
+    request: &RabbitMqSuperStreamRequest,
+    streams: &mut Vec<String>,
+) -> Result<(), RabbitMqClientError> {
+    for (order, binding_key) in binding_keys.into_iter().enumerate() {
+        let stream = format!("{}-{}", name, binding_key);
+
+        let mut arguments = request.arguments.clone();
+        arguments.insert(
+            "x-queue-type".to_string(),
+            RabbitMqArgument::String("stream".to_string()),
+        );
+
+        client
+            .create_queue(
+                vhost.to_string(),
+                stream.clone(),
+                RabbitMqQueueRequest {
+                    auto_delete: false,
+                    durable: true,
+                    arguments: Some(arguments),
+                    node: None,
+                },
+            )
+            .await?;
+        streams.push(stream.clone());
+
+        client
+            .create_binding(
+                vhost.to_string(),
+                name.to_string(),
+                stream,
+                RabbitMqBindingDestinationType::Queue,
+                RabbitMqBindingRequest {
+                    routing_key: Some(binding_key),
+                    arguments: Some(HashMap::from([(
+                        PARTITION_ORDER_ARGUMENT.to_string(),
+                        RabbitMqArgument::Decimal(Decimal::from(order)),
+                    )])),
+                },
+            )
+            .await?;
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+pub struct RabbitMqSuperStreamRequest {
+    pub partitions: RabbitMqSuperStreamPartitions,
+    pub arguments: HashMap<String, RabbitMqArgument>,
+}
+
+#[derive(Debug, Clone)]
+pub enum RabbitMqSuperStreamPartitions {
+    Count(u32),
+    BindingKeys(Vec<String>),
+}
+
+impl RabbitMqSuperStreamPartitions {
+    fn binding_keys(&self) -> Vec<String> {
+        match self {
+            RabbitMqSuperStreamPartitions::Count(count) => {
+                (0..*count).map(|i| i.to_string()).collect()
+            }
+            RabbitMqSuperStreamPartitions::BindingKeys(keys) => keys.clone(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct RabbitMqSuperStream {
+    pub name: String,
+    pub vhost: String,
+    pub partitions: Vec<RabbitMqSuperStreamPartition>,
+}
+
+#[derive(Debug)]
+pub struct RabbitMqSuperStreamPartition {
+    pub stream: String,
+    pub binding_key: String,
+    pub order: Option<i64>,
+}
@@ -1,5 +1,6 @@
 use crate::api::_generic::{handle_empty_response, handle_response};
-use crate::api::permission::{RabbitMqPermission, RabbitMqTopicPermission};
+use crate::api::_url::api_url;
+use crate::api::permission::{PermissionApi, RabbitMqPermission, RabbitMqTopicPermission};
 use crate::errors::RabbitMqClientError;
 use crate::RabbitMqClient;
 use async_trait::async_trait;
@@ -16,6 +17,15 @@ pub trait UserApi {
     async fn create_user(&self, user: RabbitMqUserCreateRequest)
         -> Result<(), RabbitMqClientError>;
 
+    async fn update_user(&self, user: RabbitMqUserUpdateRequest)
+        -> Result<(), RabbitMqClientError>;
+
+    async fn change_password(
+        &self,
+        name: String,
+        password: String,
+    ) -> Result<(), RabbitMqClientError>;
+
     async fn delete_user(&self, name: String) -> Result<(), RabbitMqClientError>;
 
     async fn list_users_without_permissions(
@@ -36,6 +46,8 @@ pub trait UserApi {
         &self,
         user: String,
     ) -> Result<Vec<RabbitMqTopicPermission>, RabbitMqClientError>;
+
+    async fn clear_user_permissions(&self, user: String) -> Result<(), RabbitMqClientError>;
 }
 
 #[async_trait]
@@ -44,7 +56,7 @@ impl UserApi for RabbitMqClient {
     async fn who_am_i(&self) -> Result<RabbitMqWhoAmI, RabbitMqClientError> {
         let response = self
             .client
-            .request(reqwest::Method::GET, format!("{}/api/whoami", self.api_url))
+            .request(reqwest::Method::GET, api_url!(self, "/api/whoami"))
             .send()
             .await?;
 
@@ -55,7 +67,7 @@ impl UserApi for RabbitMqClient {
     async fn list_users(&self) -> Result<Vec<RabbitMqUser>, RabbitMqClientError> {
         let response = self
             .client
-            .request(reqwest::Method::GET, format!("{}/api/users", self.api_url))
+            .request(reqwest::Method::GET, api_url!(self, "/api/users"))
             .send()
             .await?;
 
@@ -66,10 +78,7 @@ impl UserApi for RabbitMqClient {
     async fn get_user(&self, name: String) -> Result<RabbitMqUser, RabbitMqClientError> {
         let response = self
             .client
-            .request(
-                reqwest::Method::GET,
-                format!("{}/api/users/{}", self.api_url, name),
-            )
+            .request(reqwest::Method::GET, api_url!(self, "/api/users/{}", name))
             .send()
             .await?;
 
@@ -84,7 +93,7 @@ impl UserApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::PUT,
-                format!("{}/api/users/{}", self.api_url, user.name),
+                api_url!(self, "/api/users/{}", user.name),
             )
             .json(&user)
             .send()
@@ -93,12 +102,74 @@ impl UserApi for RabbitMqClient {
         handle_empty_response(response).await
     }
 
+    #[tracing::instrument(skip(self, user))]
+    async fn update_user(
+        &self,
+        user: RabbitMqUserUpdateRequest,
+    ) -> Result<(), RabbitMqClientError> {
+        if user.password.is_some()
+            && (user.password_hash.is_some() || user.hashing_algorithm.is_some())
+        {
+            return Err(RabbitMqClientError::InvalidRequest(format!(
+                "{} user: password cannot be combined with password_hash or hashing_algorithm",
+                user.name
+            )));
+        }
+
+        let existing = self.get_user(user.name.clone()).await?;
+
+        let (password, password_hash, hashing_algorithm) = match (user.password, user.password_hash)
+        {
+            (Some(password), None) => (Some(password), None, None),
+            (_, Some(password_hash)) => (None, Some(password_hash), user.hashing_algorithm),
+            (None, None) => (
+                None,
+                Some(existing.password_hash),
+                Some(existing.hashing_algorithm),
+            ),
+        };
+
+        let response = self
+            .client
+            .request(
+                reqwest::Method::PUT,
+                api_url!(self, "/api/users/{}", user.name),
+            )
+            .json(&RabbitMqUserCreateRequest {
+                name: user.name.clone(),
+                password,
+                password_hash,
+                hashing_algorithm,
+                tags: user.tags.unwrap_or(existing.tags),
+            })
+            .send()
+            .await?;
+
+        handle_empty_response(response).await
+    }
+
+    #[tracing::instrument(skip(self, password))]
+    async fn change_password(
+        &self,
+        name: String,
+        password: String,
+    ) -> Result<(), RabbitMqClientError> {
+        self.update_user(RabbitMqUserUpdateRequest {
+            name,
+            password: Some(password),
+            password_hash: None,
+            hashing_algorithm: None,
+            tags: None,
+        })
+        .await
+    }
+
     async fn delete_user(&self, name: String) -> Result<(), RabbitMqClientError> {
         let response = self
             .client
             .request(
                 reqwest::Method::DELETE,
-                format!("{}/api/users/{}", self.api_url, name),
+                api_url!(self, "/api/users/{}", name),
             )
             .send()
             .await?;
@@ -114,7 +185,7 @@ impl UserApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::GET,
-                format!("{}/api/users/without-permissions", self.api_url),
+                api_url!(self, "/api/users/without-permissions"),
             )
             .send()
             .await?;
@@ -131,7 +202,7 @@ impl UserApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::POST,
-                format!("{}/api/users/bulk-delete", self.api_url),
+                api_url!(self, "/api/users/bulk-delete"),
             )
             .json(&users)
             .send()
@@ -148,8 +219,8 @@ impl UserApi for RabbitMqClient {
         let response = self
             .client
             .request(
-                reqwest::Method::DELETE,
-                format!("{}/api/users/{}/permissions", self.api_url, user),
+                reqwest::Method::GET,
+                api_url!(self, "/api/users/{}/permissions", user),
             )
             .send()
             .await?;
@@ -165,14 +236,37 @@ impl UserApi for RabbitMqClient {
         let response = self
             .client
             .request(
-                reqwest::Method::DELETE,
-                format!("{}/api/users/{}/topic-permissions", self.api_url, user),
+                reqwest::Method::GET,
+                api_url!(self, "/api/users/{}/topic-permissions", user),
             )
             .send()
             .await?;
 
         handle_response(response).await
     }
+
+    #[tracing::instrument(skip(self))]
+    async fn clear_user_permissions(&self, user: String) -> Result<(), RabbitMqClientError> {
+        for permission in self.list_user_permissions(user.clone()).await? {
+            self.delete_permission(permission.vhost, user.clone())
+                .await?;
+        }
+
+        let mut vhosts: Vec<String> = self
+            .list_user_topic_permissions(user.clone())
+            .await?
+            .into_iter()
+            .map(|permission| permission.vhost)
+            .collect();
+        vhosts.sort();
+        vhosts.dedup();
+
+        for vhost in vhosts {
+            self.delete_topic_permission(vhost, user.clone()).await?;
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -181,7 +275,7 @@ pub struct RabbitMqWhoAmI {
     pub tags: Vec<RabbitMqUserTag>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct RabbitMqUser {
     pub name: String,
     pub password_hash: String,
@@ -189,12 +283,41 @@ pub struct RabbitMqUser {
     pub tags: Vec<RabbitMqUserTag>,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(from = "String", into = "String")]
 pub enum RabbitMqUserTag {
     Administrator,
     Management,
     Monitoring,
+    Policymaker,
+    Impersonator,
+    Other(String),
+}
+
+impl From<String> for RabbitMqUserTag {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "administrator" => RabbitMqUserTag::Administrator,
+            "management" => RabbitMqUserTag::Management,
+            "monitoring" => RabbitMqUserTag::Monitoring,
+            "policymaker" => RabbitMqUserTag::Policymaker,
+            "impersonator" => RabbitMqUserTag::Impersonator,
+            _ => RabbitMqUserTag::Other(value),
+        }
+    }
+}
+
+impl From<RabbitMqUserTag> for String {
+    fn from(value: RabbitMqUserTag) -> Self {
+        match value {
+            RabbitMqUserTag::Administrator => "administrator".to_string(),
+            RabbitMqUserTag::Management => "management".to_string(),
+            RabbitMqUserTag::Monitoring => "monitoring".to_string(),
+            RabbitMqUserTag::Policymaker => "policymaker".to_string(),
+            RabbitMqUserTag::Impersonator => "impersonator".to_string(),
+            RabbitMqUserTag::Other(tag) => tag,
+        }
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -210,12 +333,21 @@ pub struct RabbitMqUserCreateRequest {
     pub tags: Vec<RabbitMqUserTag>,
 }
 
+#[derive(Debug)]
+pub struct RabbitMqUserUpdateRequest {
+    pub name: String,
+    pub password: Option<String>,
+    pub password_hash: Option<String>,
+    pub hashing_algorithm: Option<RabbitMqHashingAlgorithm>,
+    pub tags: Option<Vec<RabbitMqUserTag>>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct RabbitMqUsersBulkDeleteRequest {
     pub users: Vec<String>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub enum RabbitMqHashingAlgorithm {
     #[serde(rename = "rabbit_password_hashing_sha256")]
     RabbitPasswordHashingSha256,
@@ -0,0 +1,169 @@
+use crate::api::_generic::{handle_empty_response, handle_response};
+use crate::api::_url::api_url;
+use crate::errors::RabbitMqClientError;
+use crate::RabbitMqClient;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+#[async_trait]
+pub trait UserLimitApi {
+    async fn list_user_limits(&self) -> Result<Vec<RabbitMqUserLimitsEntry>, RabbitMqClientError>;
+
+    async fn get_user_limits(
+        &self,
+        user: String,
+    ) -> Result<RabbitMqUserLimits, RabbitMqClientError>;
+
+    async fn set_user_limit(
+        &self,
+        user: String,
+        limit: RabbitMqUserLimit,
+        value: i64,
+    ) -> Result<(), RabbitMqClientError>;
+
+    async fn set_user_limits(
+        &self,
+        user: String,
+        limits: RabbitMqUserLimits,
+    ) -> Result<(), RabbitMqClientError>;
+
+    async fn delete_user_limit(
+        &self,
+        user: String,
+        limit: RabbitMqUserLimit,
+    ) -> Result<(), RabbitMqClientError>;
+}
+
+#[async_trait]
+impl UserLimitApi for RabbitMqClient {
+    #[tracing::instrument(skip(self))]
+    async fn list_user_limits(&self) -> Result<Vec<RabbitMqUserLimitsEntry>, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(reqwest::Method::GET, api_url!(self, "/api/user-limits"))
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_user_limits(
+        &self,
+        user: String,
+    ) -> Result<RabbitMqUserLimits, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/user-limits/{}", user),
+            )
+            .send()
+            .await?;
+
+        let entries: Vec<RabbitMqUserLimitsEntry> = handle_response(response).await?;
+
+        Ok(entries
+            .into_iter()
+            .next()
+            .map(|entry| entry.value)
+            .unwrap_or_default())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn set_user_limit(
+        &self,
+        user: String,
+        limit: RabbitMqUserLimit,
+        value: i64,
+    ) -> Result<(), RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::PUT,
+                api_url!(self, "/api/user-limits/{}/{}", user, limit),
+            )
+            .json(&RabbitMqUserLimitRequest { value })
+            .send()
+            .await?;
+
+        handle_empty_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn set_user_limits(
+        &self,
+        user: String,
+        limits: RabbitMqUserLimits,
+    ) -> Result<(), RabbitMqClientError> {
+        if let Some(max_connections) = limits.max_connections {
+            self.set_user_limit(
+                user.clone(),
+                RabbitMqUserLimit::MaxConnections,
+                max_connections,
+            )
+            .await?;
+        }
+
+        if let Some(max_channels) = limits.max_channels {
+            self.set_user_limit(user, RabbitMqUserLimit::MaxChannels, max_channels)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_user_limit(
+        &self,
+        user: String,
+        limit: RabbitMqUserLimit,
+    ) -> Result<(), RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::DELETE,
+                api_url!(self, "/api/user-limits/{}/{}", user, limit),
+            )
+            .send()
+            .await?;
+
+        handle_empty_response(response).await
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqUserLimitsEntry {
+    pub user: String,
+    pub value: RabbitMqUserLimits,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct RabbitMqUserLimits {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_connections: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_channels: Option<i64>,
+}
+
+#[derive(Debug, Clone)]
+pub enum RabbitMqUserLimit {
+    MaxConnections,
+    MaxChannels,
+}
+
+impl Display for RabbitMqUserLimit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RabbitMqUserLimit::MaxConnections => write!(f, "max-connections"),
+            RabbitMqUserLimit::MaxChannels => write!(f, "max-channels"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct RabbitMqUserLimitRequest {
+    value: i64,
+}
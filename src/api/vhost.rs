@@ -1,8 +1,11 @@
 use crate::api::_generic::{handle_empty_response, handle_response};
+use crate::api::_url::api_url;
 use crate::api::permission::{RabbitMqPermission, RabbitMqTopicPermission};
+use crate::api::vhost_limit::{RabbitMqVhostLimits, VhostLimitApi};
 use crate::errors::RabbitMqClientError;
 use crate::RabbitMqClient;
 use async_trait::async_trait;
+use http::StatusCode;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -18,6 +21,11 @@ pub trait VhostApi {
 
     async fn delete_vhost(&self, vhost: String) -> Result<(), RabbitMqClientError>;
 
+    async fn protect_vhost_from_deletion(&self, vhost: String) -> Result<(), RabbitMqClientError>;
+
+    async fn unprotect_vhost_from_deletion(&self, vhost: String)
+        -> Result<(), RabbitMqClientError>;
+
     async fn start_vhost_on_node(
         &self,
         vhost: String,
@@ -41,11 +49,31 @@ impl VhostApi for RabbitMqClient {
     async fn list_vhosts(&self) -> Result<Vec<RabbitMqVhost>, RabbitMqClientError> {
         let response = self
             .client
-            .request(reqwest::Method::GET, format!("{}/api/vhosts", self.api_url))
+            .request(reqwest::Method::GET, api_url!(self, "/api/vhosts"))
             .send()
             .await?;
 
-        handle_response(response).await
+        let mut vhosts: Vec<RabbitMqVhost> = handle_response(response).await?;
+
+        let mut limits: Option<HashMap<String, RabbitMqVhostLimits>> =
+            match self.list_vhost_limits().await {
+                Ok(entries) => Some(
+                    entries
+                        .into_iter()
+                        .map(|entry| (entry.vhost, entry.value))
+                        .collect(),
+                ),
+                Err(e) if is_access_denied(&e) => None,
+                Err(e) => return Err(e),
+            };
+
+        for vhost in vhosts.iter_mut() {
+            vhost.limits = limits
+                .as_mut()
+                .map(|limits| limits.remove(&vhost.name).unwrap_or_default());
+        }
+
+        Ok(vhosts)
     }
 
     #[tracing::instrument(skip(self))]
@@ -54,12 +82,19 @@ impl VhostApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::GET,
-                format!("{}/api/vhosts/{}", self.api_url, vhost),
+                api_url!(self, "/api/vhosts/{}", vhost),
             )
             .send()
             .await?;
 
-        handle_response(response).await
+        let mut result: RabbitMqVhost = handle_response(response).await?;
+        result.limits = match self.get_vhost_limits(vhost).await {
+            Ok(limits) => Some(limits),
+            Err(e) if is_access_denied(&e) => None,
+            Err(e) => return Err(e),
+        };
+
+        Ok(result)
     }
 
     #[tracing::instrument(skip(self))]
@@ -83,23 +118,32 @@ impl VhostApi for RabbitMqClient {
             description: Option<String>,
             tags: String,
             tracing: bool,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            default_queue_type: Option<String>,
         }
 
         let response = self
             .client
             .request(
                 reqwest::Method::PUT,
-                format!("{}/api/vhosts/{}", self.api_url, request.name),
+                api_url!(self, "/api/vhosts/{}", request.name),
             )
             .json(&RequestBody {
                 description: request.description,
                 tags: request.tags.join(","),
                 tracing: request.tracing,
+                default_queue_type: request.default_queue_type,
             })
             .send()
             .await?;
 
-        handle_empty_response(response).await
+        handle_empty_response(response).await?;
+
+        if let Some(limits) = request.limits {
+            self.set_vhost_limits(request.name, limits).await?;
+        }
+
+        Ok(())
     }
 
     #[tracing::instrument(skip(self))]
@@ -108,7 +152,38 @@ impl VhostApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::DELETE,
-                format!("{}/api/vhosts/{}", self.api_url, vhost),
+                api_url!(self, "/api/vhosts/{}", vhost),
+            )
+            .send()
+            .await?;
+
+        handle_empty_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn protect_vhost_from_deletion(&self, vhost: String) -> Result<(), RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::POST,
+                api_url!(self, "/api/vhosts/{}/deletion/protection", vhost),
+            )
+            .send()
+            .await?;
+
+        handle_empty_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn unprotect_vhost_from_deletion(
+        &self,
+        vhost: String,
+    ) -> Result<(), RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::DELETE,
+                api_url!(self, "/api/vhosts/{}/deletion/protection", vhost),
             )
             .send()
             .await?;
@@ -126,7 +201,7 @@ impl VhostApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::POST,
-                format!("{}/api/vhosts/{}/start/{}", self.api_url, vhost, node),
+                api_url!(self, "/api/vhosts/{}/start/{}", vhost, node),
             )
             .send()
             .await?;
@@ -143,7 +218,7 @@ impl VhostApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::GET,
-                format!("{}/api/vhosts/{}/permissions", self.api_url, vhost),
+                api_url!(self, "/api/vhosts/{}/permissions", vhost),
             )
             .send()
             .await?;
@@ -160,7 +235,7 @@ impl VhostApi for RabbitMqClient {
             .client
             .request(
                 reqwest::Method::GET,
-                format!("{}/api/vhosts/{}/topic-permissions", self.api_url, vhost),
+                api_url!(self, "/api/vhosts/{}/topic-permissions", vhost),
             )
             .send()
             .await?;
@@ -169,6 +244,14 @@ impl VhostApi for RabbitMqClient {
     }
 }
 
+fn is_access_denied(error: &RabbitMqClientError) -> bool {
+    match error {
+        RabbitMqClientError::Unauthorized => true,
+        RabbitMqClientError::ApiError(e) => e.code == StatusCode::FORBIDDEN,
+        _ => false,
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct RabbitMqVhost {
     pub cluster_state: HashMap<String, String>,
@@ -184,12 +267,18 @@ pub struct RabbitMqVhost {
     pub messages_ready: i64,
     #[serde(default)]
     pub messages_unacknowledged: i64,
+    #[serde(skip)]
+    pub limits: Option<RabbitMqVhostLimits>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct RabbitMqVhostMetadata {
     pub description: String,
     pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_queue_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protected_from_deletion: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -198,4 +287,6 @@ pub struct RabbitMqVhostRequest {
     pub description: Option<String>,
     pub tags: Vec<String>,
     pub tracing: bool,
+    pub default_queue_type: Option<String>,
+    pub limits: Option<RabbitMqVhostLimits>,
 }
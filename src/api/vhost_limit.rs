@@ -0,0 +1,172 @@
+use crate::api::_generic::{handle_empty_response, handle_response};
+use crate::api::_url::api_url;
+use crate::errors::RabbitMqClientError;
+use crate::RabbitMqClient;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+#[async_trait]
+pub trait VhostLimitApi {
+    async fn list_vhost_limits(&self)
+        -> Result<Vec<RabbitMqVhostLimitsEntry>, RabbitMqClientError>;
+
+    async fn get_vhost_limits(
+        &self,
+        vhost: String,
+    ) -> Result<RabbitMqVhostLimits, RabbitMqClientError>;
+
+    async fn set_vhost_limit(
+        &self,
+        vhost: String,
+        limit: RabbitMqVhostLimit,
+        value: i64,
+    ) -> Result<(), RabbitMqClientError>;
+
+    async fn set_vhost_limits(
+        &self,
+        vhost: String,
+        limits: RabbitMqVhostLimits,
+    ) -> Result<(), RabbitMqClientError>;
+
+    async fn delete_vhost_limit(
+        &self,
+        vhost: String,
+        limit: RabbitMqVhostLimit,
+    ) -> Result<(), RabbitMqClientError>;
+}
+
+#[async_trait]
+impl VhostLimitApi for RabbitMqClient {
+    #[tracing::instrument(skip(self))]
+    async fn list_vhost_limits(
+        &self,
+    ) -> Result<Vec<RabbitMqVhostLimitsEntry>, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(reqwest::Method::GET, api_url!(self, "/api/vhost-limits"))
+            .send()
+            .await?;
+
+        handle_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_vhost_limits(
+        &self,
+        vhost: String,
+    ) -> Result<RabbitMqVhostLimits, RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::GET,
+                api_url!(self, "/api/vhost-limits/{}", vhost),
+            )
+            .send()
+            .await?;
+
+        let entries: Vec<RabbitMqVhostLimitsEntry> = handle_response(response).await?;
+
+        Ok(entries
+            .into_iter()
+            .next()
+            .map(|entry| entry.value)
+            .unwrap_or_default())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn set_vhost_limit(
+        &self,
+        vhost: String,
+        limit: RabbitMqVhostLimit,
+        value: i64,
+    ) -> Result<(), RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::PUT,
+                api_url!(self, "/api/vhost-limits/{}/{}", vhost, limit),
+            )
+            .json(&RabbitMqVhostLimitRequest { value })
+            .send()
+            .await?;
+
+        handle_empty_response(response).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn set_vhost_limits(
+        &self,
+        vhost: String,
+        limits: RabbitMqVhostLimits,
+    ) -> Result<(), RabbitMqClientError> {
+        if let Some(max_connections) = limits.max_connections {
+            self.set_vhost_limit(
+                vhost.clone(),
+                RabbitMqVhostLimit::MaxConnections,
+                max_connections,
+            )
+            .await?;
+        }
+
+        if let Some(max_queues) = limits.max_queues {
+            self.set_vhost_limit(vhost, RabbitMqVhostLimit::MaxQueues, max_queues)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_vhost_limit(
+        &self,
+        vhost: String,
+        limit: RabbitMqVhostLimit,
+    ) -> Result<(), RabbitMqClientError> {
+        let response = self
+            .client
+            .request(
+                reqwest::Method::DELETE,
+                api_url!(self, "/api/vhost-limits/{}/{}", vhost, limit),
+            )
+            .send()
+            .await?;
+
+        handle_empty_response(response).await
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RabbitMqVhostLimitsEntry {
+    pub vhost: String,
+    pub value: RabbitMqVhostLimits,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct RabbitMqVhostLimits {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_connections: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_queues: Option<i64>,
+}
+
+#[derive(Debug, Clone)]
+pub enum RabbitMqVhostLimit {
+    MaxConnections,
+    MaxQueues,
+}
+
+impl Display for RabbitMqVhostLimit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RabbitMqVhostLimit::MaxConnections => write!(f, "max-connections"),
+            RabbitMqVhostLimit::MaxQueues => write!(f, "max-queues"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct RabbitMqVhostLimitRequest {
+    value: i64,
+}
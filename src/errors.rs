@@ -10,6 +10,8 @@ pub enum RabbitMqClientError {
     AlreadyExists(String),
     #[error("Resource not found: {0}")]
     NotFound(String),
+    #[error("Invalid request: {0}")]
+    InvalidRequest(String),
     #[error("Invalid RabbitMq API url: {0}")]
     InvalidApiUrl(String),
     #[error("Failed to parse the API response: {0}")]
@@ -8,6 +8,7 @@ pub mod api;
 pub mod config;
 pub mod errors;
 mod middlewares;
+pub mod password;
 
 #[derive(Clone)]
 pub struct RabbitMqClient {
@@ -0,0 +1,65 @@
+use crate::api::user::{RabbitMqHashingAlgorithm, RabbitMqUser};
+use crate::errors::RabbitMqClientError;
+use base64::{engine::general_purpose, Engine as _};
+use md5::Md5;
+use sha2::{Digest, Sha256, Sha512};
+
+const SALT_LENGTH: usize = 4;
+
+pub fn hash_password(password: &str, algorithm: &RabbitMqHashingAlgorithm) -> String {
+    hash_password_with_salt(password, rand::random(), algorithm)
+}
+
+pub fn hash_password_with_salt(
+    password: &str,
+    salt: [u8; SALT_LENGTH],
+    algorithm: &RabbitMqHashingAlgorithm,
+) -> String {
+    let mut salted = salt.to_vec();
+    salted.extend_from_slice(password.as_bytes());
+
+    let mut hash = salt.to_vec();
+    hash.extend(digest(&salted, algorithm));
+
+    general_purpose::STANDARD.encode(hash)
+}
+
+pub fn verify_password(
+    password: &str,
+    password_hash: &str,
+    algorithm: &RabbitMqHashingAlgorithm,
+) -> Result<bool, RabbitMqClientError> {
+    let decoded = general_purpose::STANDARD
+        .decode(password_hash)
+        .map_err(|e| {
+            RabbitMqClientError::InvalidRequest(format!("invalid password hash: {}", e))
+        })?;
+
+    if decoded.len() <= SALT_LENGTH {
+        return Err(RabbitMqClientError::InvalidRequest(
+            "invalid password hash: too short".to_string(),
+        ));
+    }
+
+    let (salt, hash) = decoded.split_at(SALT_LENGTH);
+
+    let mut salted = salt.to_vec();
+    salted.extend_from_slice(password.as_bytes());
+
+    Ok(digest(&salted, algorithm) == hash)
+}
+
+pub fn verify_user_password(
+    password: &str,
+    user: &RabbitMqUser,
+) -> Result<bool, RabbitMqClientError> {
+    verify_password(password, &user.password_hash, &user.hashing_algorithm)
+}
+
+fn digest(data: &[u8], algorithm: &RabbitMqHashingAlgorithm) -> Vec<u8> {
+    match algorithm {
+        RabbitMqHashingAlgorithm::RabbitPasswordHashingSha256 => Sha256::digest(data).to_vec(),
+        RabbitMqHashingAlgorithm::RabbitPasswordHashingSha512 => Sha512::digest(data).to_vec(),
+        RabbitMqHashingAlgorithm::RabbitPasswordHashingMd5 => Md5::digest(data).to_vec(),
+    }
+}
@@ -0,0 +1,51 @@
+use crate::context::TestContext;
+use rabbitmq_management_client::api::auth_attempt::AuthAttemptApi;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn can_get_auth_attempts() {
+    let ctx = TestContext::new();
+
+    let attempts = ctx
+        .rabbitmq
+        .get_auth_attempts("rabbit@rabbitmq".to_string())
+        .await
+        .expect("failed to get auth attempts");
+
+    assert!(attempts
+        .iter()
+        .all(|a| a.auth_attempts == a.auth_attempts_failed + a.auth_attempts_succeeded));
+}
+
+#[tokio::test]
+async fn can_get_auth_attempts_by_source() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/api/auth/attempts/rabbit%40rabbitmq/source"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                "remote_address": "10.0.0.12",
+                "username": "guest",
+                "protocol": "amqp091",
+                "auth_attempts": 120,
+                "auth_attempts_failed": 118,
+                "auth_attempts_succeeded": 2
+            }])),
+        )
+        .mount(&server)
+        .await;
+
+    let attempts = ctx
+        .rabbitmq
+        .get_auth_attempts_by_source("rabbit@rabbitmq".to_string())
+        .await
+        .expect("failed to get auth attempts by source");
+
+    assert_eq!(attempts.len(), 1);
+    assert_eq!(attempts[0].remote_address, "10.0.0.12");
+    assert_eq!(attempts[0].username, "guest");
+    assert_eq!(attempts[0].auth_attempts_failed, 118);
+}
@@ -3,7 +3,7 @@ use rabbitmq_management_client::api::binding::{
     BindingApi, RabbitMqBindingDestinationType, RabbitMqBindingRequest,
 };
 use rabbitmq_management_client::api::exchange::{ExchangeApi, RabbitMqExchangeRequest};
-use rabbitmq_management_client::api::queue::{QueueApi, RabbitMqQueueRequest};
+use rabbitmq_management_client::api::queue::{QueueApi, RabbitMqArgument, RabbitMqQueueRequest};
 use rabbitmq_management_client::errors::RabbitMqClientError;
 use std::collections::HashMap;
 
@@ -27,6 +27,7 @@ async fn can_create_and_list_bindings() {
                 auto_delete: true,
                 durable: false,
                 internal: false,
+                arguments: None,
             },
         )
         .await
@@ -42,6 +43,7 @@ async fn can_create_and_list_bindings() {
                 auto_delete: true,
                 durable: false,
                 internal: false,
+                arguments: None,
             },
         )
         .await
@@ -101,6 +103,7 @@ async fn can_filter_bindings() {
                 auto_delete: true,
                 durable: false,
                 internal: false,
+                arguments: None,
             },
         )
         .await
@@ -131,7 +134,10 @@ async fn can_filter_bindings() {
             RabbitMqBindingDestinationType::Queue,
             RabbitMqBindingRequest {
                 routing_key: Some("test-queue-routing".to_string()),
-                arguments: Some(HashMap::from([("foo".to_string(), "bar".to_string())])),
+                arguments: Some(HashMap::from([(
+                    "foo".to_string(),
+                    RabbitMqArgument::String("bar".to_string()),
+                )])),
             },
         )
         .await
@@ -180,6 +186,7 @@ async fn can_get_and_delete_binding() {
                 auto_delete: true,
                 durable: false,
                 internal: false,
+                arguments: None,
             },
         )
         .await
@@ -195,6 +202,7 @@ async fn can_get_and_delete_binding() {
                 auto_delete: true,
                 durable: false,
                 internal: false,
+                arguments: None,
             },
         )
         .await
@@ -300,6 +308,7 @@ async fn cannot_create_binding_if_source_exchange_not_found() {
                 auto_delete: true,
                 durable: false,
                 internal: false,
+                arguments: None,
             },
         )
         .await
@@ -347,6 +356,7 @@ async fn cannot_create_binding_if_destination_exchange_not_found() {
                 auto_delete: true,
                 durable: false,
                 internal: false,
+                arguments: None,
             },
         )
         .await
@@ -393,6 +403,7 @@ async fn cannot_create_binding_if_destination_queue_not_found() {
                 auto_delete: true,
                 durable: false,
                 internal: false,
+                arguments: None,
             },
         )
         .await
@@ -439,6 +450,7 @@ async fn cannot_delete_binding_not_found() {
                 auto_delete: true,
                 durable: false,
                 internal: false,
+                arguments: None,
             },
         )
         .await
@@ -454,6 +466,7 @@ async fn cannot_delete_binding_not_found() {
                 auto_delete: true,
                 durable: false,
                 internal: false,
+                arguments: None,
             },
         )
         .await
@@ -0,0 +1,166 @@
+use crate::context::TestContext;
+use rabbitmq_management_client::api::channel::{ChannelApi, RabbitMqChannelState};
+use rabbitmq_management_client::api::{RabbitMqRequestOptions, RabbitMqSorting};
+use rabbitmq_management_client::errors::RabbitMqClientError;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn can_list_channels() {
+    let ctx = TestContext::new();
+
+    let vhost = ctx
+        .create_random_vhost()
+        .await
+        .expect("failed to create vhost");
+
+    let channels = ctx
+        .rabbitmq
+        .list_channels(
+            Some(vhost.name.clone()),
+            Some(RabbitMqRequestOptions {
+                sorting: Some(RabbitMqSorting {
+                    key: Some("name".to_string()),
+                    reversed: false,
+                }),
+                ..Default::default()
+            }),
+        )
+        .await
+        .expect("failed to list vhost channels");
+
+    assert!(channels.items.is_empty());
+
+    ctx.rabbitmq
+        .list_channels(None, None)
+        .await
+        .expect("failed to list channels");
+
+    ctx.delete_vhost(vhost.name)
+        .await
+        .expect("failed to delete vhost");
+}
+
+#[tokio::test]
+async fn returns_not_found() {
+    let ctx = TestContext::new();
+
+    let result = ctx.rabbitmq.get_channel("doesnotexist".to_string()).await;
+    assert!(matches!(result, Err(RabbitMqClientError::NotFound(_))));
+
+    let result = ctx
+        .rabbitmq
+        .list_connection_channels("doesnotexist".to_string())
+        .await;
+    assert!(matches!(result, Err(RabbitMqClientError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn deserializes_channels() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/api/channels"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "filtered_count": 1,
+            "item_count": 1,
+            "page": 1,
+            "page_count": 1,
+            "page_size": 50,
+            "total_count": 1,
+            "items": [{
+                "acks_uncommitted": 0,
+                "confirm": true,
+                "connection_details": {
+                    "name": "172.18.0.1:53012 -> 172.18.0.2:5672",
+                    "peer_host": "172.18.0.1",
+                    "peer_port": 53012
+                },
+                "consumer_count": 1,
+                "consumer_details": [{
+                    "ack_required": true,
+                    "arguments": {
+                        "x-priority": 10
+                    },
+                    "consumer_tag": "amq.ctag-billing",
+                    "exclusive": false,
+                    "prefetch_count": 20,
+                    "queue": {
+                        "name": "billing",
+                        "vhost": "/"
+                    }
+                }],
+                "garbage_collection": {
+                    "fullsweep_after": 65535,
+                    "max_heap_size": 0,
+                    "min_bin_vheap_size": 46422,
+                    "min_heap_size": 233,
+                    "minor_gcs": 4
+                },
+                "global_prefetch_count": 0,
+                "idle_since": "2024-06-10 06:13:20",
+                "message_stats": {
+                    "ack": 42,
+                    "ack_details": { "rate": 0.0 },
+                    "deliver": 42,
+                    "deliver_get": 42,
+                    "publish": 7,
+                    "redeliver": 1
+                },
+                "messages_unacknowledged": 3,
+                "messages_uncommitted": 0,
+                "messages_unconfirmed": 0,
+                "name": "172.18.0.1:53012 -> 172.18.0.2:5672 (1)",
+                "node": "rabbit@rabbitmq",
+                "number": 1,
+                "pending_raft_commands": 0,
+                "prefetch_count": 20,
+                "state": "running",
+                "transactional": false,
+                "user": "guest",
+                "user_who_performed_action": "guest",
+                "vhost": "/"
+            }]
+        })))
+        .mount(&server)
+        .await;
+
+    let channels = ctx
+        .rabbitmq
+        .list_channels(None, None)
+        .await
+        .expect("failed to list channels");
+
+    assert_eq!(channels.items.len(), 1);
+
+    let channel = &channels.items[0];
+    assert_eq!(channel.number, 1);
+    assert_eq!(channel.state, Some(RabbitMqChannelState::Running));
+    assert!(channel.confirm);
+    assert!(!channel.transactional);
+    assert_eq!(channel.messages_unacknowledged, Some(3));
+
+    let connection = channel
+        .connection_details
+        .as_ref()
+        .expect("missing connection details");
+    assert_eq!(connection.name, "172.18.0.1:53012 -> 172.18.0.2:5672");
+    assert_eq!(connection.peer_host, Some("172.18.0.1".to_string()));
+    assert_eq!(connection.peer_port, Some(53012));
+
+    assert_eq!(channel.consumer_details.len(), 1);
+    let consumer = &channel.consumer_details[0];
+    assert_eq!(consumer.consumer_tag, "amq.ctag-billing");
+    assert_eq!(consumer.queue.name, "billing");
+    assert_eq!(consumer.prefetch_count, 20);
+    assert!(consumer.arguments.contains_key("x-priority"));
+
+    let stats = channel
+        .message_stats
+        .as_ref()
+        .expect("missing message stats");
+    assert_eq!(stats.ack, 42);
+    assert_eq!(stats.publish, 7);
+    assert_eq!(stats.confirm, 0);
+}
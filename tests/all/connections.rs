@@ -0,0 +1,154 @@
+use crate::context::TestContext;
+use rabbitmq_management_client::api::connection::{ConnectionApi, RabbitMqConnectionState};
+use rabbitmq_management_client::errors::RabbitMqClientError;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn can_list_connections() {
+    let ctx = TestContext::new();
+
+    let vhost = ctx
+        .create_random_vhost()
+        .await
+        .expect("failed to create vhost");
+
+    let connections = ctx
+        .rabbitmq
+        .list_connections(Some(vhost.name.clone()), None)
+        .await
+        .expect("failed to list vhost connections");
+
+    assert!(connections.items.is_empty());
+
+    ctx.rabbitmq
+        .list_connections(None, None)
+        .await
+        .expect("failed to list connections");
+
+    ctx.delete_vhost(vhost.name)
+        .await
+        .expect("failed to delete vhost");
+}
+
+#[tokio::test]
+async fn returns_not_found() {
+    let ctx = TestContext::new();
+
+    let result = ctx
+        .rabbitmq
+        .get_connection("doesnotexist".to_string())
+        .await;
+    assert!(matches!(result, Err(RabbitMqClientError::NotFound(_))));
+
+    let result = ctx
+        .rabbitmq
+        .close_connection(
+            "doesnotexist".to_string(),
+            Some("closed by test".to_string()),
+        )
+        .await;
+    assert!(matches!(result, Err(RabbitMqClientError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn deserializes_connections() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/api/connections"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "filtered_count": 1,
+            "item_count": 1,
+            "page": 1,
+            "page_count": 1,
+            "page_size": 50,
+            "total_count": 1,
+            "items": [{
+                "auth_mechanism": "PLAIN",
+                "channel_max": 2047,
+                "channels": 1,
+                "client_properties": {
+                    "capabilities": {
+                        "authentication_failure_close": true,
+                        "basic.nack": true,
+                        "connection.blocked": true,
+                        "consumer_cancel_notify": true,
+                        "publisher_confirms": true
+                    },
+                    "connection_name": "billing-worker",
+                    "copyright": "Copyright (c) 2007-2024 Broadcom Inc.",
+                    "information": "Licensed under the MPL 2.0.",
+                    "platform": "Java",
+                    "product": "RabbitMQ",
+                    "version": "5.21.0",
+                    "hostname": "billing-7d9f"
+                },
+                "connected_at": 1718000000000u64,
+                "frame_max": 131072,
+                "host": "172.18.0.2",
+                "name": "172.18.0.1:53012 -> 172.18.0.2:5672",
+                "node": "rabbit@rabbitmq",
+                "peer_cert_issuer": null,
+                "peer_cert_subject": null,
+                "peer_cert_validity": null,
+                "peer_host": "172.18.0.1",
+                "peer_port": 53012,
+                "port": 5672,
+                "protocol": "AMQP 0-9-1",
+                "recv_cnt": 12,
+                "recv_oct": 1520,
+                "send_cnt": 9,
+                "send_oct": 980,
+                "send_pend": 0,
+                "ssl": false,
+                "ssl_cipher": null,
+                "ssl_hash": null,
+                "ssl_key_exchange": null,
+                "ssl_protocol": null,
+                "state": "running",
+                "timeout": 60,
+                "type": "network",
+                "user": "guest",
+                "user_provided_name": "billing-worker",
+                "vhost": "/"
+            }]
+        })))
+        .mount(&server)
+        .await;
+
+    let connections = ctx
+        .rabbitmq
+        .list_connections(None, None)
+        .await
+        .expect("failed to list connections");
+
+    assert_eq!(connections.items.len(), 1);
+
+    let connection = &connections.items[0];
+    assert_eq!(connection.name, "172.18.0.1:53012 -> 172.18.0.2:5672");
+    assert_eq!(connection.kind, "network");
+    assert_eq!(connection.state, Some(RabbitMqConnectionState::Running));
+    assert_eq!(
+        connection.connected_at.map(|c| c.timestamp_millis()),
+        Some(1718000000000)
+    );
+    assert!(!connection.tls.ssl);
+    assert_eq!(connection.tls.ssl_protocol, None);
+    assert_eq!(
+        connection.client_properties.connection_name,
+        Some("billing-worker".to_string())
+    );
+    assert_eq!(
+        connection
+            .client_properties
+            .capabilities
+            .get("publisher_confirms"),
+        Some(&true)
+    );
+    assert_eq!(
+        connection.client_properties.extra_properties["hostname"],
+        "billing-7d9f"
+    );
+}
@@ -0,0 +1,126 @@
+use crate::context::TestContext;
+use rabbitmq_management_client::api::consumer::{ConsumerApi, RabbitMqConsumerActivityStatus};
+use rabbitmq_management_client::api::queue::{QueueApi, RabbitMqQueueRequest};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn can_list_consumers() {
+    let ctx = TestContext::new();
+
+    let vhost = ctx
+        .create_random_vhost()
+        .await
+        .expect("failed to create vhost");
+
+    ctx.rabbitmq
+        .create_queue(
+            vhost.name.clone(),
+            "test-consumers".to_string(),
+            RabbitMqQueueRequest {
+                auto_delete: false,
+                durable: false,
+                arguments: None,
+                node: None,
+            },
+        )
+        .await
+        .expect("failed to create queue");
+
+    let consumers = ctx
+        .rabbitmq
+        .list_consumers(Some(vhost.name.clone()))
+        .await
+        .expect("failed to list vhost consumers");
+
+    assert!(consumers.is_empty());
+
+    ctx.rabbitmq
+        .list_consumers(None)
+        .await
+        .expect("failed to list consumers");
+
+    ctx.delete_vhost(vhost.name)
+        .await
+        .expect("failed to delete vhost");
+}
+
+#[tokio::test]
+async fn deserializes_consumers() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    let channel_details = serde_json::json!({
+        "connection_name": "172.18.0.1:53012 -> 172.18.0.2:5672",
+        "name": "172.18.0.1:53012 -> 172.18.0.2:5672 (1)",
+        "node": "rabbit@rabbitmq",
+        "number": 1,
+        "peer_host": "172.18.0.1",
+        "peer_port": 53012,
+        "user": "guest"
+    });
+
+    Mock::given(method("GET"))
+        .and(path("/api/consumers/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {
+                "ack_required": true,
+                "active": true,
+                "activity_status": "up",
+                "arguments": {},
+                "channel_details": channel_details,
+                "consumer_tag": "amq.ctag-billing",
+                "consumer_timeout": 1800000,
+                "exclusive": false,
+                "prefetch_count": 20,
+                "queue": { "name": "billing", "vhost": "/" }
+            },
+            {
+                "ack_required": false,
+                "active": false,
+                "activity_status": "waiting",
+                "arguments": { "x-priority": 5 },
+                "channel_details": channel_details,
+                "consumer_tag": "amq.ctag-audit",
+                "exclusive": false,
+                "prefetch_count": 0,
+                "queue": { "name": "audit", "vhost": "/" }
+            }
+        ])))
+        .mount(&server)
+        .await;
+
+    let consumers = ctx
+        .rabbitmq
+        .list_consumers(None)
+        .await
+        .expect("failed to list consumers");
+
+    assert_eq!(consumers.len(), 2);
+
+    let billing = &consumers[0];
+    assert_eq!(billing.consumer_tag, "amq.ctag-billing");
+    assert_eq!(billing.queue.name, "billing");
+    assert_eq!(billing.active, Some(true));
+    assert_eq!(
+        billing.activity_status,
+        Some(RabbitMqConsumerActivityStatus::Up)
+    );
+    assert_eq!(billing.consumer_timeout, Some(1800000));
+    assert_eq!(billing.channel_details.number, 1);
+    assert_eq!(billing.channel_details.node, "rabbit@rabbitmq");
+    assert_eq!(
+        billing.channel_details.connection_name,
+        "172.18.0.1:53012 -> 172.18.0.2:5672"
+    );
+    assert_eq!(billing.channel_details.peer_port, Some(53012));
+
+    let audit = &consumers[1];
+    assert_eq!(
+        audit.activity_status,
+        Some(RabbitMqConsumerActivityStatus::Waiting)
+    );
+    assert!(!audit.ack_required);
+    assert_eq!(audit.consumer_timeout, None);
+    assert!(audit.arguments.contains_key("x-priority"));
+}
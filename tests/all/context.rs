@@ -26,6 +26,17 @@ impl TestContext {
         Self { rabbitmq: rmq }
     }
 
+    pub fn new_with_api_url(api_url: String) -> Self {
+        let rmq = RabbitMqClientBuilder::new(RabbitMqConfiguration {
+            rabbitmq_api_url: api_url,
+            ..test_config()
+        })
+        .build()
+        .unwrap();
+
+        Self { rabbitmq: rmq }
+    }
+
     pub fn new_with_preset_client(client: ClientWithMiddleware) -> Self {
         let rmq = RabbitMqClientBuilder::new(test_config())
             .preset_client(client)
@@ -44,6 +55,8 @@ impl TestContext {
                 description: Some(format!("{} testing vhost", id.clone())),
                 tags: vec![],
                 tracing: false,
+                default_queue_type: None,
+                limits: None,
             })
             .await?;
 
@@ -0,0 +1,321 @@
+use crate::context::TestContext;
+use rabbitmq_management_client::api::definitions::DefinitionsApi;
+use rabbitmq_management_client::api::exchange::{ExchangeApi, RabbitMqExchangeRequest};
+use rabbitmq_management_client::api::policy::{
+    PolicyApi, RabbitMqPolicyDefinitionValue, RabbitMqPolicyRequest,
+};
+use rabbitmq_management_client::api::queue::{QueueApi, RabbitMqQueueRequest};
+use rabbitmq_management_client::api::user_limit::RabbitMqUserLimits;
+use rabbitmq_management_client::errors::RabbitMqClientError;
+use std::collections::HashMap;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn can_export_definitions() {
+    let ctx = TestContext::new();
+
+    let vhost = ctx
+        .create_random_vhost()
+        .await
+        .expect("failed to create vhost");
+
+    ctx.rabbitmq
+        .create_exchange(
+            vhost.name.clone(),
+            "test-export-exchange".to_string(),
+            RabbitMqExchangeRequest {
+                kind: "topic".to_string(),
+                auto_delete: false,
+                durable: true,
+                internal: false,
+                arguments: None,
+            },
+        )
+        .await
+        .expect("failed to create exchange");
+
+    ctx.rabbitmq
+        .create_queue(
+            vhost.name.clone(),
+            "test-export-queue".to_string(),
+            RabbitMqQueueRequest {
+                auto_delete: false,
+                durable: true,
+                arguments: None,
+                node: None,
+            },
+        )
+        .await
+        .expect("failed to create queue");
+
+    ctx.rabbitmq
+        .create_policy(
+            vhost.name.clone(),
+            "test-export-policy".to_string(),
+            RabbitMqPolicyRequest {
+                pattern: "^test-export".to_string(),
+                definition: HashMap::from([(
+                    "max-length".to_string(),
+                    RabbitMqPolicyDefinitionValue::Integer(100),
+                )]),
+                priority: Some(1),
+                apply_to: Some("queues".to_string()),
+            },
+        )
+        .await
+        .expect("failed to create policy");
+
+    let definitions = ctx
+        .rabbitmq
+        .export_definitions(None)
+        .await
+        .expect("failed to export definitions");
+
+    assert!(definitions.vhosts.iter().any(|v| v.name == vhost.name));
+    assert!(definitions.users.iter().any(|u| u.name == "guest"));
+    assert!(definitions
+        .permissions
+        .iter()
+        .any(|p| p.user == "guest" && p.vhost == vhost.name));
+
+    let queue = definitions
+        .queues
+        .iter()
+        .find(|q| q.name == "test-export-queue" && q.vhost.as_ref() == Some(&vhost.name))
+        .expect("the created queue was not exported");
+    assert!(queue.durable);
+    assert!(!queue.auto_delete);
+
+    let exchange = definitions
+        .exchanges
+        .iter()
+        .find(|e| e.name == "test-export-exchange" && e.vhost.as_ref() == Some(&vhost.name))
+        .expect("the created exchange was not exported");
+    assert_eq!(exchange.kind, "topic");
+    assert!(exchange.durable);
+
+    let policy = definitions
+        .policies
+        .iter()
+        .find(|p| p.name == "test-export-policy" && p.vhost.as_ref() == Some(&vhost.name))
+        .expect("the created policy was not exported");
+    assert_eq!(policy.pattern, "^test-export");
+    assert_eq!(policy.apply_to, "queues");
+    assert_eq!(policy.priority, 1);
+    assert_eq!(
+        policy.definition.get("max-length"),
+        Some(&RabbitMqPolicyDefinitionValue::Integer(100))
+    );
+
+    let json = serde_json::to_value(&definitions).expect("failed to serialize definitions");
+    assert!(json["queues"]
+        .as_array()
+        .expect("queues should serialize as a list")
+        .iter()
+        .any(|q| q["name"] == "test-export-queue" && q["vhost"] == vhost.name.as_str()));
+    assert!(json["policies"]
+        .as_array()
+        .expect("policies should serialize as a list")
+        .iter()
+        .any(|p| p["name"] == "test-export-policy" && p["definition"]["max-length"] == 100));
+
+    ctx.delete_vhost(vhost.name)
+        .await
+        .expect("failed to delete vhost");
+}
+
+#[tokio::test]
+async fn keeps_user_limits_and_complex_policy_values() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/api/definitions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "rabbitmq_version": "3.13.7",
+            "users": [{
+                "name": "service-account",
+                "password_hash": "kI3GCqW5JLMJa4iX1lo7X4D6XbYqlLgxIs30+P6tENUV2POR",
+                "hashing_algorithm": "rabbit_password_hashing_sha256",
+                "tags": ["management"],
+                "limits": {
+                    "max-connections": 10,
+                    "max-channels": 50
+                }
+            }],
+            "policies": [{
+                "vhost": "/",
+                "name": "tuned",
+                "pattern": "^tuned",
+                "apply-to": "queues",
+                "priority": 0,
+                "definition": {
+                    "x-custom-ratio": 0.75,
+                    "x-custom-settings": {
+                        "mode": "lazy",
+                        "retries": 3
+                    }
+                }
+            }]
+        })))
+        .mount(&server)
+        .await;
+
+    let definitions = ctx
+        .rabbitmq
+        .export_definitions(None)
+        .await
+        .expect("failed to export definitions");
+
+    assert_eq!(
+        definitions.users[0].limits,
+        Some(RabbitMqUserLimits {
+            max_connections: Some(10),
+            max_channels: Some(50),
+        })
+    );
+
+    let definition = &definitions.policies[0].definition;
+    assert_eq!(
+        definition.get("x-custom-ratio"),
+        Some(&RabbitMqPolicyDefinitionValue::Float(0.75))
+    );
+    assert_eq!(
+        definition.get("x-custom-settings"),
+        Some(&RabbitMqPolicyDefinitionValue::Object(HashMap::from([
+            (
+                "mode".to_string(),
+                RabbitMqPolicyDefinitionValue::String("lazy".to_string())
+            ),
+            (
+                "retries".to_string(),
+                RabbitMqPolicyDefinitionValue::Integer(3)
+            ),
+        ])))
+    );
+
+    let json = serde_json::to_value(&definitions).expect("failed to serialize definitions");
+    assert_eq!(json["users"][0]["limits"]["max-connections"], 10);
+    assert_eq!(json["users"][0]["limits"]["max-channels"], 50);
+    assert_eq!(json["policies"][0]["definition"]["x-custom-ratio"], 0.75);
+    assert_eq!(
+        json["policies"][0]["definition"]["x-custom-settings"]["retries"],
+        3
+    );
+}
+
+#[tokio::test]
+async fn can_round_trip_vhost_definitions() {
+    let ctx = TestContext::new();
+
+    let vhost = ctx
+        .create_random_vhost()
+        .await
+        .expect("failed to create vhost");
+
+    ctx.rabbitmq
+        .create_exchange(
+            vhost.name.clone(),
+            "test-definitions-exchange".to_string(),
+            RabbitMqExchangeRequest {
+                kind: "topic".to_string(),
+                auto_delete: false,
+                durable: true,
+                internal: false,
+                arguments: None,
+            },
+        )
+        .await
+        .expect("failed to create exchange");
+
+    ctx.rabbitmq
+        .create_queue(
+            vhost.name.clone(),
+            "test-definitions-queue".to_string(),
+            RabbitMqQueueRequest {
+                auto_delete: false,
+                durable: true,
+                arguments: None,
+                node: None,
+            },
+        )
+        .await
+        .expect("failed to create queue");
+
+    ctx.rabbitmq
+        .create_policy(
+            vhost.name.clone(),
+            "test-definitions-policy".to_string(),
+            RabbitMqPolicyRequest {
+                pattern: "^test-definitions".to_string(),
+                definition: HashMap::from([(
+                    "max-length".to_string(),
+                    RabbitMqPolicyDefinitionValue::Integer(100),
+                )]),
+                priority: Some(1),
+                apply_to: Some("queues".to_string()),
+            },
+        )
+        .await
+        .expect("failed to create policy");
+
+    let definitions = ctx
+        .rabbitmq
+        .export_definitions(Some(vhost.name.clone()))
+        .await
+        .expect("failed to export vhost definitions");
+
+    assert_eq!(definitions.queues.len(), 1);
+    assert_eq!(definitions.exchanges.len(), 1);
+    assert_eq!(definitions.policies.len(), 1);
+    assert_eq!(
+        definitions.policies[0].definition.get("max-length"),
+        Some(&RabbitMqPolicyDefinitionValue::Integer(100))
+    );
+
+    // Remove everything and restore it from the exported definitions
+    ctx.rabbitmq
+        .delete_queue(vhost.name.clone(), "test-definitions-queue".to_string())
+        .await
+        .expect("failed to delete queue");
+    ctx.rabbitmq
+        .delete_exchange(vhost.name.clone(), "test-definitions-exchange".to_string())
+        .await
+        .expect("failed to delete exchange");
+    ctx.rabbitmq
+        .delete_policy(vhost.name.clone(), "test-definitions-policy".to_string())
+        .await
+        .expect("failed to delete policy");
+
+    let deleted_queue = ctx
+        .rabbitmq
+        .get_queue(vhost.name.clone(), "test-definitions-queue".to_string())
+        .await;
+    assert!(matches!(
+        deleted_queue,
+        Err(RabbitMqClientError::NotFound(_))
+    ));
+
+    ctx.rabbitmq
+        .import_definitions(Some(vhost.name.clone()), definitions)
+        .await
+        .expect("failed to import vhost definitions");
+
+    ctx.rabbitmq
+        .get_queue(vhost.name.clone(), "test-definitions-queue".to_string())
+        .await
+        .expect("failed to get the restored queue");
+    ctx.rabbitmq
+        .get_exchange(vhost.name.clone(), "test-definitions-exchange".to_string())
+        .await
+        .expect("failed to get the restored exchange");
+    ctx.rabbitmq
+        .get_policy(vhost.name.clone(), "test-definitions-policy".to_string())
+        .await
+        .expect("failed to get the restored policy");
+
+    ctx.delete_vhost(vhost.name)
+        .await
+        .expect("failed to delete vhost");
+}
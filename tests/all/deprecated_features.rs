@@ -0,0 +1,25 @@
+use crate::context::TestContext;
+use rabbitmq_management_client::api::deprecated_feature::DeprecatedFeatureApi;
+
+#[tokio::test]
+async fn can_list_deprecated_features() {
+    let ctx = TestContext::new();
+
+    let features = ctx
+        .rabbitmq
+        .list_deprecated_features()
+        .await
+        .expect("failed to list deprecated features");
+
+    assert!(features.iter().any(|f| f.name == "classic_queue_mirroring"));
+
+    let used = ctx
+        .rabbitmq
+        .list_deprecated_features_in_use()
+        .await
+        .expect("failed to list deprecated features in use");
+
+    assert!(used
+        .iter()
+        .all(|u| features.iter().any(|f| f.name == u.name)));
+}
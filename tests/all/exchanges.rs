@@ -37,6 +37,7 @@ async fn can_list_exchanges_paginated() {
                     auto_delete: true,
                     durable: false,
                     internal: false,
+                    arguments: None,
                 },
             )
             .await
@@ -85,6 +86,7 @@ async fn can_filter_exchanges() {
                     auto_delete: true,
                     durable: false,
                     internal: false,
+                    arguments: None,
                 },
             )
             .await
@@ -135,6 +137,7 @@ async fn can_regex_filter_exchanges() {
                     auto_delete: true,
                     durable: false,
                     internal: false,
+                    arguments: None,
                 },
             )
             .await
@@ -185,6 +188,7 @@ async fn can_crud_exchange() {
                 auto_delete: true,
                 durable: false,
                 internal: false,
+                arguments: None,
             },
         )
         .await
@@ -232,6 +236,7 @@ async fn cannot_create_if_exchange_exists() {
                 auto_delete: true,
                 durable: false,
                 internal: false,
+                arguments: None,
             },
         )
         .await
@@ -248,6 +253,7 @@ async fn cannot_create_if_exchange_exists() {
                 auto_delete: true,
                 durable: false,
                 internal: false,
+                arguments: None,
             },
         )
         .await;
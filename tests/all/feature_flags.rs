@@ -0,0 +1,42 @@
+use crate::context::TestContext;
+use rabbitmq_management_client::api::feature_flag::{
+    FeatureFlagApi, RabbitMqFeatureFlagStability, RabbitMqFeatureFlagState,
+};
+
+#[tokio::test]
+async fn can_list_feature_flags() {
+    let ctx = TestContext::new();
+
+    let flags = ctx
+        .rabbitmq
+        .list_feature_flags()
+        .await
+        .expect("failed to list feature flags");
+
+    assert!(!flags.is_empty());
+    assert!(flags
+        .iter()
+        .filter(|f| f.stability == RabbitMqFeatureFlagStability::Required)
+        .all(|f| f.state == RabbitMqFeatureFlagState::Enabled));
+}
+
+#[tokio::test]
+async fn can_enable_all_stable_feature_flags() {
+    let ctx = TestContext::new();
+
+    ctx.rabbitmq
+        .enable_all_stable_feature_flags()
+        .await
+        .expect("failed to enable the stable feature flags");
+
+    let flags = ctx
+        .rabbitmq
+        .list_feature_flags()
+        .await
+        .expect("failed to list feature flags");
+
+    assert!(flags
+        .iter()
+        .filter(|f| f.stability == RabbitMqFeatureFlagStability::Stable)
+        .all(|f| f.state == RabbitMqFeatureFlagState::Enabled));
+}
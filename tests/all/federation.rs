@@ -0,0 +1,247 @@
+use crate::context::TestContext;
+use rabbitmq_management_client::api::exchange::{ExchangeApi, RabbitMqExchangeRequest};
+use rabbitmq_management_client::api::federation::{
+    FederationApi, RabbitMqFederationAckMode, RabbitMqFederationLinkStatus,
+    RabbitMqFederationLinkType, RabbitMqFederationUpstream, RabbitMqFederationUpstreamSetMember,
+};
+use rabbitmq_management_client::api::policy::{
+    PolicyApi, RabbitMqPolicyDefinitionValue, RabbitMqPolicyRequest,
+};
+use rabbitmq_management_client::errors::RabbitMqClientError;
+use std::collections::HashMap;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn can_crud_federation_upstreams() {
+    let ctx = TestContext::new();
+
+    let vhost = ctx
+        .create_random_vhost()
+        .await
+        .expect("failed to create vhost");
+
+    ctx.rabbitmq
+        .create_federation_upstream(
+            vhost.name.clone(),
+            "test-upstream".to_string(),
+            RabbitMqFederationUpstream {
+                uri: vec!["amqp://localhost".to_string()],
+                ack_mode: Some(RabbitMqFederationAckMode::OnConfirm),
+                max_hops: Some(2),
+                message_ttl: Some(60000),
+                trust_user_id: Some(false),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect("failed to create federation upstream");
+
+    let upstream = ctx
+        .rabbitmq
+        .get_federation_upstream(vhost.name.clone(), "test-upstream".to_string())
+        .await
+        .expect("failed to get federation upstream");
+
+    assert_eq!(upstream.value.uri, vec!["amqp://localhost".to_string()]);
+    assert_eq!(upstream.value.max_hops, Some(2));
+    assert_eq!(
+        upstream.value.ack_mode,
+        Some(RabbitMqFederationAckMode::OnConfirm)
+    );
+
+    ctx.rabbitmq
+        .create_federation_upstream_set(
+            vhost.name.clone(),
+            "test-upstream-set".to_string(),
+            vec![RabbitMqFederationUpstreamSetMember {
+                upstream: "test-upstream".to_string(),
+                exchange: None,
+                queue: None,
+            }],
+        )
+        .await
+        .expect("failed to create federation upstream set");
+
+    let sets = ctx
+        .rabbitmq
+        .list_federation_upstream_sets(Some(vhost.name.clone()))
+        .await
+        .expect("failed to list federation upstream sets");
+
+    assert_eq!(sets.len(), 1);
+    assert_eq!(sets[0].value[0].upstream, "test-upstream");
+
+    ctx.rabbitmq
+        .delete_federation_upstream_set(vhost.name.clone(), "test-upstream-set".to_string())
+        .await
+        .expect("failed to delete federation upstream set");
+
+    ctx.rabbitmq
+        .delete_federation_upstream(vhost.name.clone(), "test-upstream".to_string())
+        .await
+        .expect("failed to delete federation upstream");
+
+    let deleted = ctx
+        .rabbitmq
+        .get_federation_upstream(vhost.name.clone(), "test-upstream".to_string())
+        .await;
+    assert!(matches!(deleted, Err(RabbitMqClientError::NotFound(_))));
+
+    ctx.delete_vhost(vhost.name)
+        .await
+        .expect("failed to delete vhost");
+}
+
+#[tokio::test]
+async fn can_list_federation_links() {
+    let ctx = TestContext::new();
+
+    let vhost = ctx
+        .create_random_vhost()
+        .await
+        .expect("failed to create vhost");
+
+    ctx.rabbitmq
+        .create_exchange(
+            vhost.name.clone(),
+            "test-federated".to_string(),
+            RabbitMqExchangeRequest {
+                kind: "topic".to_string(),
+                auto_delete: false,
+                durable: true,
+                internal: false,
+                arguments: None,
+            },
+        )
+        .await
+        .expect("failed to create exchange");
+
+    ctx.rabbitmq
+        .create_federation_upstream(
+            vhost.name.clone(),
+            "test-upstream".to_string(),
+            RabbitMqFederationUpstream {
+                uri: vec!["amqp://localhost".to_string()],
+                ..Default::default()
+            },
+        )
+        .await
+        .expect("failed to create federation upstream");
+
+    ctx.rabbitmq
+        .create_policy(
+            vhost.name.clone(),
+            "test-federation".to_string(),
+            RabbitMqPolicyRequest {
+                pattern: "^test-federated$".to_string(),
+                definition: HashMap::from([(
+                    "federation-upstream-set".to_string(),
+                    RabbitMqPolicyDefinitionValue::String("all".to_string()),
+                )]),
+                priority: None,
+                apply_to: Some("exchanges".to_string()),
+            },
+        )
+        .await
+        .expect("failed to create federation policy");
+
+    let links = ctx
+        .rabbitmq
+        .list_federation_links(Some(vhost.name.clone()))
+        .await
+        .expect("failed to list federation links");
+
+    assert!(links.iter().all(|l| l.upstream == "test-upstream"));
+
+    ctx.delete_vhost(vhost.name)
+        .await
+        .expect("failed to delete vhost");
+}
+
+#[tokio::test]
+async fn deserializes_federation_link_statuses() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    let link = |id: &str, status: serde_json::Value| {
+        let mut link = serde_json::json!({
+            "id": id,
+            "node": "rabbit@rabbitmq",
+            "vhost": "/",
+            "upstream": "origin",
+            "type": "exchange",
+            "exchange": "federated",
+            "upstream_exchange": "federated",
+            "uri": "amqp://origin",
+            "local_connection": "<rabbit@rabbitmq.1718000000.1234.0>",
+            "timestamp": "2024-06-10 06:13:20"
+        });
+        link.as_object_mut()
+            .unwrap()
+            .extend(status.as_object().unwrap().clone());
+        link
+    };
+
+    Mock::given(method("GET"))
+        .and(path("/api/federation-links/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            link("a1", serde_json::json!({ "status": "starting" })),
+            link("b2", serde_json::json!({ "status": "running" })),
+            link(
+                "c3",
+                serde_json::json!({
+                    "status": "error",
+                    "error": "{{badmatch,{error,econnrefused}}}"
+                })
+            ),
+            link("d4", serde_json::json!({ "status": "shutdown" })),
+        ])))
+        .mount(&server)
+        .await;
+
+    let links = ctx
+        .rabbitmq
+        .list_federation_links(None)
+        .await
+        .expect("failed to list federation links");
+
+    assert_eq!(links.len(), 4);
+    assert!(links
+        .iter()
+        .all(|l| l.kind == RabbitMqFederationLinkType::Exchange));
+    assert_eq!(links[0].id, Some("a1".to_string()));
+    assert_eq!(links[0].status, RabbitMqFederationLinkStatus::Starting);
+    assert_eq!(links[1].status, RabbitMqFederationLinkStatus::Running);
+    assert_eq!(
+        links[2].status,
+        RabbitMqFederationLinkStatus::Error {
+            reason: "{{badmatch,{error,econnrefused}}}".to_string()
+        }
+    );
+    assert_eq!(links[3].status, RabbitMqFederationLinkStatus::Unknown);
+}
+
+#[tokio::test]
+async fn can_restart_federation_link() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    Mock::given(method("DELETE"))
+        .and(path(
+            "/api/federation-links/vhost/%2F/a1b2c3/rabbit%40rabbitmq/restart",
+        ))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    ctx.rabbitmq
+        .restart_federation_link(
+            "/".to_string(),
+            "a1b2c3".to_string(),
+            "rabbit@rabbitmq".to_string(),
+        )
+        .await
+        .expect("failed to restart federation link");
+}
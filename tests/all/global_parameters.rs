@@ -0,0 +1,53 @@
+use crate::context::TestContext;
+use rabbitmq_management_client::api::global_parameter::{
+    GlobalParameterApi, RabbitMqGlobalParameterRequest,
+};
+use rabbitmq_management_client::errors::RabbitMqClientError;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn can_list_global_parameters() {
+    let ctx = TestContext::new();
+
+    let parameters = ctx
+        .rabbitmq
+        .list_global_parameters()
+        .await
+        .expect("failed to list global parameters");
+
+    assert!(parameters.iter().any(|p| p.name == "internal_cluster_id"));
+}
+
+#[tokio::test]
+async fn can_crud_global_parameters() {
+    let ctx = TestContext::new();
+
+    let name = format!("test_{}", Uuid::new_v4());
+
+    ctx.rabbitmq
+        .set_global_parameter(
+            name.clone(),
+            RabbitMqGlobalParameterRequest {
+                value: serde_json::json!({ "region": "eu-west-1" }),
+            },
+        )
+        .await
+        .expect("failed to set global parameter");
+
+    let parameter = ctx
+        .rabbitmq
+        .get_global_parameter(name.clone())
+        .await
+        .expect("failed to get global parameter");
+
+    assert_eq!(parameter.name, name);
+    assert_eq!(parameter.value["region"], "eu-west-1");
+
+    ctx.rabbitmq
+        .delete_global_parameter(name.clone())
+        .await
+        .expect("failed to delete global parameter");
+
+    let deleted = ctx.rabbitmq.get_global_parameter(name).await;
+    assert!(matches!(deleted, Err(RabbitMqClientError::NotFound(_))));
+}
@@ -0,0 +1,91 @@
+use crate::context::TestContext;
+use rabbitmq_management_client::api::health_check::{
+    HealthCheckApi, RabbitMqHealthCheck, RabbitMqHealthCheckTimeUnit,
+};
+
+#[tokio::test]
+async fn can_run_passing_health_checks() {
+    let ctx = TestContext::new();
+
+    let alarms = ctx
+        .rabbitmq
+        .check_alarms()
+        .await
+        .expect("failed to check alarms");
+    assert!(alarms.is_passed());
+
+    let local_alarms = ctx
+        .rabbitmq
+        .check_local_alarms()
+        .await
+        .expect("failed to check local alarms");
+    assert!(local_alarms.is_passed());
+
+    let certificates = ctx
+        .rabbitmq
+        .check_certificate_expiration(1, RabbitMqHealthCheckTimeUnit::Months)
+        .await
+        .expect("failed to check certificate expiration");
+    assert!(certificates.is_passed());
+
+    let port = ctx
+        .rabbitmq
+        .check_port_listener(5672)
+        .await
+        .expect("failed to check port listener");
+    assert!(port.is_passed());
+
+    let protocol = ctx
+        .rabbitmq
+        .check_protocol_listener("amqp".to_string())
+        .await
+        .expect("failed to check protocol listener");
+    assert!(protocol.is_passed());
+
+    let vhosts = ctx
+        .rabbitmq
+        .check_virtual_hosts()
+        .await
+        .expect("failed to check virtual hosts");
+    assert!(vhosts.is_passed());
+
+    let quorum_critical = ctx
+        .rabbitmq
+        .check_node_is_quorum_critical()
+        .await
+        .expect("failed to check quorum criticality");
+    assert!(quorum_critical.is_passed());
+}
+
+#[tokio::test]
+async fn reports_failed_health_checks() {
+    let ctx = TestContext::new();
+
+    let port = ctx
+        .rabbitmq
+        .check_port_listener(1234)
+        .await
+        .expect("failed to check port listener");
+
+    match port {
+        RabbitMqHealthCheck::Passed => panic!("port 1234 should not have a listener"),
+        RabbitMqHealthCheck::Failed(failure) => {
+            assert_eq!(failure.details.missing, 1234);
+            assert!(failure.details.ports.contains(&5672));
+        }
+    }
+
+    let protocol = ctx
+        .rabbitmq
+        .check_protocol_listener("stomp".to_string())
+        .await
+        .expect("failed to check protocol listener");
+
+    match protocol {
+        RabbitMqHealthCheck::Passed => panic!("stomp should not have a listener"),
+        RabbitMqHealthCheck::Failed(failure) => {
+            assert_eq!(failure.details.missing, "stomp");
+            assert!(failure.details.protocols.contains(&"amqp".to_string()));
+        }
+    }
+}
@@ -1,9 +1,30 @@
+mod auth_attempts;
 mod basic;
 mod bindings;
+mod channels;
+mod connections;
+mod consumers;
 mod context;
+mod definitions;
+mod deprecated_features;
 mod exchanges;
+mod feature_flags;
+mod federation;
+mod global_parameters;
+mod health_checks;
 mod messages;
 mod nodes;
+mod operator_policies;
+mod parameters;
+mod passwords;
+mod permissions;
 mod queues;
+mod rebalance;
+mod shovels;
+mod streams;
+mod super_streams;
+mod urls;
+mod user_limits;
 mod users;
+mod vhost_limits;
 mod vhosts;
@@ -9,7 +9,7 @@ use rabbitmq_management_client::api::message::{
     RabbitMqMessageHeader, RabbitMqMessageProperties, RabbitMqMessageProperty,
     RabbitMqPublishMessageRequest,
 };
-use rabbitmq_management_client::api::queue::{QueueApi, RabbitMqQueueRequest};
+use rabbitmq_management_client::api::queue::{QueueApi, RabbitMqArgument, RabbitMqQueueRequest};
 use rust_decimal::prelude::FromPrimitive;
 use rust_decimal::Decimal;
 use std::collections::HashMap;
@@ -33,6 +33,7 @@ async fn can_publish_message_to_exchange() {
                 auto_delete: true,
                 durable: false,
                 internal: false,
+                arguments: None,
             },
         )
         .await
@@ -86,6 +87,7 @@ async fn can_consume_messages_from_queue() {
                 auto_delete: true,
                 durable: false,
                 internal: false,
+                arguments: None,
             },
         )
         .await
@@ -115,7 +117,10 @@ async fn can_consume_messages_from_queue() {
             RabbitMqBindingDestinationType::Queue,
             RabbitMqBindingRequest {
                 routing_key: Some("test-queue-routing".to_string()),
-                arguments: Some(HashMap::from([("foo".to_string(), "bar".to_string())])),
+                arguments: Some(HashMap::from([(
+                    "foo".to_string(),
+                    RabbitMqArgument::String("bar".to_string()),
+                )])),
             },
         )
         .await
@@ -214,6 +219,7 @@ async fn can_publish_and_consume_messages_with_nested_headers() {
                 auto_delete: true,
                 durable: false,
                 internal: false,
+                arguments: None,
             },
         )
         .await
@@ -243,7 +249,10 @@ async fn can_publish_and_consume_messages_with_nested_headers() {
             RabbitMqBindingDestinationType::Queue,
             RabbitMqBindingRequest {
                 routing_key: Some("test-queue-routing".to_string()),
-                arguments: Some(HashMap::from([("foo".to_string(), "bar".to_string())])),
+                arguments: Some(HashMap::from([(
+                    "foo".to_string(),
+                    RabbitMqArgument::String("bar".to_string()),
+                )])),
             },
         )
         .await
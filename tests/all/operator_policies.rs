@@ -0,0 +1,78 @@
+use crate::context::TestContext;
+use rabbitmq_management_client::api::operator_policy::{
+    OperatorPolicyApi, RabbitMqOperatorPolicyDefinition, RabbitMqOperatorPolicyOverflow,
+    RabbitMqOperatorPolicyRequest,
+};
+use rabbitmq_management_client::errors::RabbitMqClientError;
+
+#[tokio::test]
+async fn can_crud_operator_policies() {
+    let ctx = TestContext::new();
+
+    let vhost = ctx
+        .create_random_vhost()
+        .await
+        .expect("failed to create vhost");
+
+    let definition = RabbitMqOperatorPolicyDefinition {
+        max_length: Some(1000),
+        message_ttl: Some(60000),
+        overflow: Some(RabbitMqOperatorPolicyOverflow::RejectPublish),
+        ..Default::default()
+    };
+
+    ctx.rabbitmq
+        .create_operator_policy(
+            vhost.name.clone(),
+            "test-operator-policy".to_string(),
+            RabbitMqOperatorPolicyRequest {
+                pattern: ".*".to_string(),
+                definition: definition.clone(),
+                priority: Some(1),
+                apply_to: Some("queues".to_string()),
+            },
+        )
+        .await
+        .expect("failed to create operator policy");
+
+    let policy = ctx
+        .rabbitmq
+        .get_operator_policy(vhost.name.clone(), "test-operator-policy".to_string())
+        .await
+        .expect("failed to get operator policy");
+
+    assert_eq!(policy.name, "test-operator-policy");
+    assert_eq!(policy.apply_to, "queues");
+    assert_eq!(policy.definition, definition);
+
+    let result = ctx
+        .rabbitmq
+        .create_operator_policy(
+            vhost.name.clone(),
+            "test-operator-policy".to_string(),
+            RabbitMqOperatorPolicyRequest {
+                pattern: ".*".to_string(),
+                definition,
+                priority: None,
+                apply_to: None,
+            },
+        )
+        .await;
+    assert!(matches!(result, Err(RabbitMqClientError::AlreadyExists(_))));
+
+    ctx.rabbitmq
+        .delete_operator_policy(vhost.name.clone(), "test-operator-policy".to_string())
+        .await
+        .expect("failed to delete operator policy");
+
+    let policies = ctx
+        .rabbitmq
+        .list_operator_policies(Some(vhost.name.clone()))
+        .await
+        .expect("failed to list operator policies");
+    assert!(policies.is_empty());
+
+    ctx.delete_vhost(vhost.name)
+        .await
+        .expect("failed to delete vhost");
+}
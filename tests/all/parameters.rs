@@ -0,0 +1,101 @@
+use crate::context::TestContext;
+use rabbitmq_management_client::api::parameter::{ParameterApi, RabbitMqParameterRequest};
+use rabbitmq_management_client::errors::RabbitMqClientError;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+struct TestUpstream {
+    uri: String,
+    #[serde(rename = "max-hops")]
+    max_hops: i64,
+}
+
+#[tokio::test]
+async fn can_crud_parameters() {
+    let ctx = TestContext::new();
+
+    let vhost = ctx
+        .create_random_vhost()
+        .await
+        .expect("failed to create vhost");
+
+    ctx.rabbitmq
+        .create_parameter(
+            "federation-upstream".to_string(),
+            vhost.name.clone(),
+            "test-upstream".to_string(),
+            RabbitMqParameterRequest {
+                value: TestUpstream {
+                    uri: "amqp://localhost".to_string(),
+                    max_hops: 2,
+                },
+            },
+        )
+        .await
+        .expect("failed to create parameter");
+
+    // Typed by the caller
+    let parameter = ctx
+        .rabbitmq
+        .get_parameter::<TestUpstream>(
+            "federation-upstream".to_string(),
+            vhost.name.clone(),
+            "test-upstream".to_string(),
+        )
+        .await
+        .expect("failed to get parameter");
+
+    assert_eq!(parameter.name, "test-upstream");
+    assert_eq!(parameter.component, "federation-upstream");
+    assert_eq!(parameter.value.uri, "amqp://localhost");
+    assert_eq!(parameter.value.max_hops, 2);
+
+    // Left as raw JSON
+    let parameters = ctx
+        .rabbitmq
+        .list_vhost_parameters::<serde_json::Value>(
+            "federation-upstream".to_string(),
+            vhost.name.clone(),
+        )
+        .await
+        .expect("failed to list parameters");
+
+    assert_eq!(parameters.len(), 1);
+    assert_eq!(parameters[0].value["max-hops"], 2);
+
+    let result = ctx
+        .rabbitmq
+        .create_parameter(
+            "federation-upstream".to_string(),
+            vhost.name.clone(),
+            "test-upstream".to_string(),
+            RabbitMqParameterRequest {
+                value: serde_json::json!({ "uri": "amqp://localhost" }),
+            },
+        )
+        .await;
+    assert!(matches!(result, Err(RabbitMqClientError::AlreadyExists(_))));
+
+    ctx.rabbitmq
+        .delete_parameter(
+            "federation-upstream".to_string(),
+            vhost.name.clone(),
+            "test-upstream".to_string(),
+        )
+        .await
+        .expect("failed to delete parameter");
+
+    let deleted = ctx
+        .rabbitmq
+        .get_parameter::<serde_json::Value>(
+            "federation-upstream".to_string(),
+            vhost.name.clone(),
+            "test-upstream".to_string(),
+        )
+        .await;
+    assert!(matches!(deleted, Err(RabbitMqClientError::NotFound(_))));
+
+    ctx.delete_vhost(vhost.name)
+        .await
+        .expect("failed to delete vhost");
+}
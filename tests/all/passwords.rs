@@ -0,0 +1,81 @@
+use crate::context::TestContext;
+use rabbitmq_management_client::api::user::{
+    RabbitMqHashingAlgorithm, RabbitMqUserCreateRequest, UserApi,
+};
+use rabbitmq_management_client::errors::RabbitMqClientError;
+use rabbitmq_management_client::password::{
+    hash_password, hash_password_with_salt, verify_password, verify_user_password,
+};
+use uuid::Uuid;
+
+#[test]
+fn hashes_password_with_salt() {
+    let hash = hash_password_with_salt(
+        "test12",
+        [0x90, 0x8D, 0xC6, 0x0A],
+        &RabbitMqHashingAlgorithm::RabbitPasswordHashingSha256,
+    );
+
+    assert_eq!(hash, "kI3GCqW5JLMJa4iX1lo7X4D6XbYqlLgxIs30+P6tENUV2POR");
+}
+
+#[test]
+fn verifies_hashed_passwords() {
+    for algorithm in [
+        RabbitMqHashingAlgorithm::RabbitPasswordHashingSha256,
+        RabbitMqHashingAlgorithm::RabbitPasswordHashingSha512,
+        RabbitMqHashingAlgorithm::RabbitPasswordHashingMd5,
+    ] {
+        let hash = hash_password("fake_password", &algorithm);
+
+        assert!(verify_password("fake_password", &hash, &algorithm).unwrap());
+        assert!(!verify_password("wrong_password", &hash, &algorithm).unwrap());
+    }
+}
+
+#[test]
+fn rejects_malformed_password_hashes() {
+    let algorithm = RabbitMqHashingAlgorithm::RabbitPasswordHashingSha256;
+
+    assert!(matches!(
+        verify_password("fake_password", "not base64!", &algorithm),
+        Err(RabbitMqClientError::InvalidRequest(_))
+    ));
+    assert!(matches!(
+        verify_password("fake_password", "", &algorithm),
+        Err(RabbitMqClientError::InvalidRequest(_))
+    ));
+}
+
+#[tokio::test]
+async fn can_create_user_with_local_password_hash() {
+    let ctx = TestContext::new();
+
+    let name = Uuid::new_v4().to_string();
+    let algorithm = RabbitMqHashingAlgorithm::RabbitPasswordHashingSha512;
+
+    ctx.rabbitmq
+        .create_user(RabbitMqUserCreateRequest {
+            name: name.clone(),
+            password: None,
+            password_hash: Some(hash_password("fake_password", &algorithm)),
+            hashing_algorithm: Some(algorithm),
+            tags: vec![],
+        })
+        .await
+        .expect("failed to create user");
+
+    let user = ctx
+        .rabbitmq
+        .get_user(name.clone())
+        .await
+        .expect("failed to get user");
+
+    assert!(verify_user_password("fake_password", &user).expect("failed to verify password"));
+    assert!(!verify_user_password("wrong_password", &user).expect("failed to verify password"));
+
+    ctx.rabbitmq
+        .delete_user(name)
+        .await
+        .expect("failed to delete user");
+}
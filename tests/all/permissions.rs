@@ -0,0 +1,142 @@
+use crate::context::TestContext;
+use rabbitmq_management_client::api::permission::PermissionApi;
+use rabbitmq_management_client::api::user::{RabbitMqUserCreateRequest, UserApi};
+use rabbitmq_management_client::errors::RabbitMqClientError;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn can_crud_permissions() {
+    let ctx = TestContext::new();
+
+    let vhost = ctx
+        .create_random_vhost()
+        .await
+        .expect("failed to create vhost");
+
+    let user = Uuid::new_v4().to_string();
+    ctx.rabbitmq
+        .create_user(RabbitMqUserCreateRequest {
+            name: user.clone(),
+            password: Some("fake_password".to_string()),
+            password_hash: None,
+            hashing_algorithm: None,
+            tags: vec![],
+        })
+        .await
+        .expect("failed to create user");
+
+    ctx.rabbitmq
+        .set_permission(
+            vhost.name.clone(),
+            user.clone(),
+            "^$".to_string(),
+            "^amq\\.topic$".to_string(),
+            ".*".to_string(),
+        )
+        .await
+        .expect("failed to set permission");
+
+    let permission = ctx
+        .rabbitmq
+        .get_permission(vhost.name.clone(), user.clone())
+        .await
+        .expect("failed to get permission");
+
+    assert_eq!(permission.configure, "^$");
+    assert_eq!(permission.write, "^amq\\.topic$");
+    assert_eq!(permission.read, ".*");
+
+    ctx.rabbitmq
+        .delete_permission(vhost.name.clone(), user.clone())
+        .await
+        .expect("failed to delete permission");
+
+    let deleted = ctx
+        .rabbitmq
+        .get_permission(vhost.name.clone(), user.clone())
+        .await;
+    assert!(matches!(deleted, Err(RabbitMqClientError::NotFound(_))));
+
+    ctx.rabbitmq
+        .delete_user(user)
+        .await
+        .expect("failed to delete user");
+    ctx.delete_vhost(vhost.name)
+        .await
+        .expect("failed to delete vhost");
+}
+
+#[tokio::test]
+async fn can_crud_topic_permissions() {
+    let ctx = TestContext::new();
+
+    let vhost = ctx
+        .create_random_vhost()
+        .await
+        .expect("failed to create vhost");
+
+    let user = Uuid::new_v4().to_string();
+    ctx.rabbitmq
+        .create_user(RabbitMqUserCreateRequest {
+            name: user.clone(),
+            password: Some("fake_password".to_string()),
+            password_hash: None,
+            hashing_algorithm: None,
+            tags: vec![],
+        })
+        .await
+        .expect("failed to create user");
+
+    ctx.rabbitmq
+        .set_topic_permission(
+            vhost.name.clone(),
+            user.clone(),
+            "amq.topic".to_string(),
+            "^orders\\.".to_string(),
+            ".*".to_string(),
+        )
+        .await
+        .expect("failed to set topic permission");
+
+    let permissions = ctx
+        .rabbitmq
+        .get_topic_permission(vhost.name.clone(), user.clone())
+        .await
+        .expect("failed to get topic permissions");
+
+    assert_eq!(permissions.len(), 1);
+    assert_eq!(permissions[0].exchange, "amq.topic");
+    assert_eq!(permissions[0].write, "^orders\\.");
+    assert_eq!(permissions[0].read, ".*");
+
+    let all_permissions = ctx
+        .rabbitmq
+        .list_topic_permissions()
+        .await
+        .expect("failed to list topic permissions");
+    assert!(all_permissions
+        .iter()
+        .any(|p| p.vhost == vhost.name && p.user == user));
+
+    ctx.rabbitmq
+        .delete_topic_permission(vhost.name.clone(), user.clone())
+        .await
+        .expect("failed to delete topic permission");
+
+    let deleted = ctx
+        .rabbitmq
+        .get_topic_permission(vhost.name.clone(), user.clone())
+        .await;
+    assert!(match deleted {
+        Ok(permissions) => permissions.is_empty(),
+        Err(e) => matches!(e, RabbitMqClientError::NotFound(_)),
+    });
+
+    ctx.rabbitmq
+        .delete_user(user)
+        .await
+        .expect("failed to delete user");
+    ctx.delete_vhost(vhost.name)
+        .await
+        .expect("failed to delete vhost");
+}
@@ -8,12 +8,19 @@ use rabbitmq_management_client::api::message::{
     RabbitMqGetMessagesOptions, RabbitMqMessageEncoding, RabbitMqMessageProperties,
     RabbitMqPublishMessageRequest,
 };
-use rabbitmq_management_client::api::queue::{QueueApi, RabbitMqQueueAction, RabbitMqQueueRequest};
+use rabbitmq_management_client::api::queue::{
+    QueueApi, RabbitMqArgument, RabbitMqQueueAction, RabbitMqQueueRequest,
+    RabbitMqQuorumQueueGrowRequest, RabbitMqQuorumQueueGrowStrategy,
+};
 use rabbitmq_management_client::api::{
     RabbitMqPagination, RabbitMqPaginationFilter, RabbitMqRequestOptions, RabbitMqSorting,
 };
 use rabbitmq_management_client::errors::RabbitMqClientError;
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
 use std::collections::HashMap;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[tokio::test]
 async fn can_list_queues() {
@@ -46,7 +53,10 @@ async fn can_list_queues() {
             RabbitMqQueueRequest {
                 auto_delete: true,
                 durable: true,
-                arguments: Some(HashMap::from([("foo".to_string(), "bar".to_string())])),
+                arguments: Some(HashMap::from([(
+                    "foo".to_string(),
+                    RabbitMqArgument::String("bar".to_string()),
+                )])),
                 node: None,
             },
         )
@@ -302,7 +312,10 @@ async fn cannot_create_queue_that_already_exists() {
             RabbitMqQueueRequest {
                 auto_delete: true,
                 durable: true,
-                arguments: Some(HashMap::from([("foo".to_string(), "bar".to_string())])),
+                arguments: Some(HashMap::from([(
+                    "foo".to_string(),
+                    RabbitMqArgument::String("bar".to_string()),
+                )])),
                 node: None,
             },
         )
@@ -334,6 +347,7 @@ async fn can_get_queue_bindings() {
                 auto_delete: true,
                 durable: false,
                 internal: false,
+                arguments: None,
             },
         )
         .await
@@ -363,7 +377,10 @@ async fn can_get_queue_bindings() {
             RabbitMqBindingDestinationType::Queue,
             RabbitMqBindingRequest {
                 routing_key: Some("test-queue-routing".to_string()),
-                arguments: Some(HashMap::from([("foo".to_string(), "bar".to_string())])),
+                arguments: Some(HashMap::from([(
+                    "foo".to_string(),
+                    RabbitMqArgument::String("bar".to_string()),
+                )])),
             },
         )
         .await
@@ -453,6 +470,7 @@ async fn can_purge_queue() {
                 auto_delete: true,
                 durable: false,
                 internal: false,
+                arguments: None,
             },
         )
         .await
@@ -482,7 +500,10 @@ async fn can_purge_queue() {
             RabbitMqBindingDestinationType::Queue,
             RabbitMqBindingRequest {
                 routing_key: Some("test-queue-routing".to_string()),
-                arguments: Some(HashMap::from([("foo".to_string(), "bar".to_string())])),
+                arguments: Some(HashMap::from([(
+                    "foo".to_string(),
+                    RabbitMqArgument::String("bar".to_string()),
+                )])),
             },
         )
         .await
@@ -594,6 +615,7 @@ async fn can_sort_queues() {
                 auto_delete: true,
                 durable: false,
                 internal: false,
+                arguments: None,
             },
         )
         .await
@@ -651,7 +673,10 @@ async fn can_sort_queues() {
             RabbitMqBindingDestinationType::Queue,
             RabbitMqBindingRequest {
                 routing_key: Some("test-queue-routing-1".to_string()),
-                arguments: Some(HashMap::from([("foo".to_string(), "bar".to_string())])),
+                arguments: Some(HashMap::from([(
+                    "foo".to_string(),
+                    RabbitMqArgument::String("bar".to_string()),
+                )])),
             },
         )
         .await
@@ -665,7 +690,10 @@ async fn can_sort_queues() {
             RabbitMqBindingDestinationType::Queue,
             RabbitMqBindingRequest {
                 routing_key: Some("test-queue-routing-2".to_string()),
-                arguments: Some(HashMap::from([("foo".to_string(), "bar".to_string())])),
+                arguments: Some(HashMap::from([(
+                    "foo".to_string(),
+                    RabbitMqArgument::String("bar".to_string()),
+                )])),
             },
         )
         .await
@@ -679,7 +707,10 @@ async fn can_sort_queues() {
             RabbitMqBindingDestinationType::Queue,
             RabbitMqBindingRequest {
                 routing_key: Some("test-queue-routing-3".to_string()),
-                arguments: Some(HashMap::from([("foo".to_string(), "bar".to_string())])),
+                arguments: Some(HashMap::from([(
+                    "foo".to_string(),
+                    RabbitMqArgument::String("bar".to_string()),
+                )])),
             },
         )
         .await
@@ -794,3 +825,185 @@ async fn can_sort_queues() {
         .await
         .expect("failed to delete vhost");
 }
+
+#[tokio::test]
+async fn exposes_quorum_queue_membership() {
+    let ctx = TestContext::new();
+
+    let vhost = ctx
+        .create_random_vhost()
+        .await
+        .expect("failed to create vhost");
+
+    ctx.rabbitmq
+        .create_queue(
+            vhost.name.clone(),
+            "test-quorum-queue".to_string(),
+            RabbitMqQueueRequest {
+                auto_delete: false,
+                durable: true,
+                arguments: Some(HashMap::from([(
+                    "x-queue-type".to_string(),
+                    RabbitMqArgument::String("quorum".to_string()),
+                )])),
+                node: None,
+            },
+        )
+        .await
+        .expect("failed to create queue");
+
+    let queue = ctx
+        .rabbitmq
+        .get_queue(vhost.name.clone(), "test-quorum-queue".to_string())
+        .await
+        .expect("failed to get queue");
+
+    assert_eq!(queue.leader, Some("rabbit@rabbitmq".to_string()));
+    assert_eq!(queue.members, vec!["rabbit@rabbitmq".to_string()]);
+    assert_eq!(queue.online, vec!["rabbit@rabbitmq".to_string()]);
+
+    ctx.delete_vhost(vhost.name)
+        .await
+        .expect("failed to delete vhost");
+}
+
+#[tokio::test]
+async fn manages_quorum_queue_replicas() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    Mock::given(method("POST"))
+        .and(path("/api/queues/quorum/orders/invoices/replicas/add"))
+        .and(body_json(serde_json::json!({ "node": "rabbit@node-2" })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/api/queues/quorum/orders/invoices/replicas/delete"))
+        .and(body_json(serde_json::json!({ "node": "rabbit@node-1" })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    ctx.rabbitmq
+        .add_quorum_queue_replica(
+            "orders".to_string(),
+            "invoices".to_string(),
+            "rabbit@node-2".to_string(),
+        )
+        .await
+        .expect("failed to add replica");
+
+    ctx.rabbitmq
+        .delete_quorum_queue_replica(
+            "orders".to_string(),
+            "invoices".to_string(),
+            "rabbit@node-1".to_string(),
+        )
+        .await
+        .expect("failed to delete replica");
+}
+
+#[tokio::test]
+async fn grows_and_shrinks_quorum_queues_on_node() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    Mock::given(method("POST"))
+        .and(path("/api/queues/quorum/replicas/on/rabbit%40node-3/grow"))
+        .and(body_json(serde_json::json!({
+            "vhost_pattern": "^orders$",
+            "queue_pattern": ".*",
+            "strategy": "even"
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(
+            "/api/queues/quorum/replicas/on/rabbit%40node-1/shrink",
+        ))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    ctx.rabbitmq
+        .grow_quorum_queues_on_node(
+            "rabbit@node-3".to_string(),
+            RabbitMqQuorumQueueGrowRequest {
+                vhost_pattern: "^orders$".to_string(),
+                queue_pattern: ".*".to_string(),
+                strategy: RabbitMqQuorumQueueGrowStrategy::Even,
+            },
+        )
+        .await
+        .expect("failed to grow quorum queues");
+
+    ctx.rabbitmq
+        .shrink_quorum_queues_on_node("rabbit@node-1".to_string())
+        .await
+        .expect("failed to shrink quorum queues");
+}
+
+#[tokio::test]
+async fn decodes_integer_arguments_as_decimals() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/api/queues/orders/billing"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "arguments": {
+                "x-max-length": 100,
+                "x-message-ttl": 60000,
+                "x-queue-type": "classic",
+                "x-single-active-consumer": true
+            },
+            "auto_delete": false,
+            "durable": true,
+            "exclusive": false,
+            "name": "billing",
+            "node": "rabbit@rabbitmq",
+            "state": "running",
+            "type": "classic",
+            "vhost": "orders"
+        })))
+        .mount(&server)
+        .await;
+
+    let queue = ctx
+        .rabbitmq
+        .get_queue("orders".to_string(), "billing".to_string())
+        .await
+        .expect("failed to get queue");
+
+    assert_eq!(
+        queue.arguments.get("x-max-length"),
+        Some(&RabbitMqArgument::Decimal(Decimal::from(100)))
+    );
+    assert_eq!(
+        queue.arguments.get("x-message-ttl"),
+        Some(&RabbitMqArgument::Decimal(Decimal::from(60000)))
+    );
+    assert_eq!(
+        queue.arguments.get("x-queue-type"),
+        Some(&RabbitMqArgument::String("classic".to_string()))
+    );
+    assert_eq!(
+        queue.arguments.get("x-single-active-consumer"),
+        Some(&RabbitMqArgument::Boolean(true))
+    );
+
+    assert_eq!(
+        serde_json::to_value(RabbitMqArgument::Decimal(Decimal::from(100))).unwrap(),
+        serde_json::json!(100)
+    );
+    assert_eq!(
+        serde_json::to_value(RabbitMqArgument::Decimal(Decimal::from_f64(0.5).unwrap())).unwrap(),
+        serde_json::json!(0.5)
+    );
+}
@@ -0,0 +1,217 @@
+use crate::context::TestContext;
+use rabbitmq_management_client::api::queue::{QueueApi, RabbitMqArgument, RabbitMqQueueRequest};
+use rabbitmq_management_client::api::rebalance::RebalanceApi;
+use std::collections::HashMap;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn can_get_queue_leader_distribution() {
+    let ctx = TestContext::new();
+
+    let vhost = ctx
+        .create_random_vhost()
+        .await
+        .expect("failed to create vhost");
+
+    ctx.rabbitmq
+        .create_queue(
+            vhost.name.clone(),
+            "test-quorum-queue".to_string(),
+            RabbitMqQueueRequest {
+                auto_delete: false,
+                durable: true,
+                arguments: Some(HashMap::from([(
+                    "x-queue-type".to_string(),
+                    RabbitMqArgument::String("quorum".to_string()),
+                )])),
+                node: None,
+            },
+        )
+        .await
+        .expect("failed to create queue");
+
+    let distribution = ctx
+        .rabbitmq
+        .get_queue_leader_distribution()
+        .await
+        .expect("failed to get queue leader distribution");
+
+    let node = distribution
+        .iter()
+        .find(|n| n.node == "rabbit@rabbitmq")
+        .expect("could not find the node in the distribution");
+
+    assert!(node.running);
+    assert!(node.by_type.get("quorum").copied().unwrap_or_default() >= 1);
+    assert_eq!(node.total, node.by_type.values().sum::<u32>());
+
+    ctx.rabbitmq
+        .rebalance_queues()
+        .await
+        .expect("failed to rebalance queues");
+
+    ctx.delete_vhost(vhost.name)
+        .await
+        .expect("failed to delete vhost");
+}
+
+#[tokio::test]
+async fn rebalances_queues() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    Mock::given(method("POST"))
+        .and(path("/api/rebalance/queues"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    ctx.rabbitmq
+        .rebalance_queues()
+        .await
+        .expect("failed to rebalance queues");
+}
+
+#[tokio::test]
+async fn counts_leaders_without_queue_stats() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/api/nodes"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .mount(&server)
+        .await;
+
+    let queue = |name: &str, node: &str, kind: &str| {
+        serde_json::json!({
+            "arguments": { "x-queue-type": kind },
+            "auto_delete": false,
+            "durable": true,
+            "exclusive": false,
+            "name": name,
+            "node": node,
+            "state": "running",
+            "type": kind,
+            "vhost": "/"
+        })
+    };
+
+    Mock::given(method("GET"))
+        .and(path("/api/queues/"))
+        .and(query_param("disable_stats", "true"))
+        .and(query_param("page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "filtered_count": 3,
+            "item_count": 3,
+            "items": [
+                queue("orders", "rabbit@node-1", "quorum"),
+                queue("invoices", "rabbit@node-1", "classic"),
+                queue("audit", "rabbit@node-2", "quorum")
+            ],
+            "page": 1,
+            "page_count": 1,
+            "page_size": 500,
+            "total_count": 3
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let distribution = ctx
+        .rabbitmq
+        .get_queue_leader_distribution()
+        .await
+        .expect("failed to get queue leader distribution");
+
+    assert_eq!(distribution.len(), 2);
+    assert_eq!(distribution[0].node, "rabbit@node-1");
+    assert_eq!(distribution[0].total, 2);
+    assert_eq!(distribution[0].by_type.get("quorum"), Some(&1));
+    assert_eq!(distribution[0].by_type.get("classic"), Some(&1));
+    assert_eq!(distribution[1].node, "rabbit@node-2");
+    assert_eq!(distribution[1].total, 1);
+}
+
+#[tokio::test]
+async fn reports_leader_distribution_around_rebalance() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/api/nodes"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .mount(&server)
+        .await;
+
+    let queue = |name: &str, node: &str| {
+        serde_json::json!({
+            "arguments": { "x-queue-type": "quorum" },
+            "auto_delete": false,
+            "durable": true,
+            "exclusive": false,
+            "name": name,
+            "node": node,
+            "state": "running",
+            "type": "quorum",
+            "vhost": "/"
+        })
+    };
+    let page = |items: Vec<serde_json::Value>| {
+        serde_json::json!({
+            "filtered_count": items.len(),
+            "item_count": items.len(),
+            "items": items,
+            "page": 1,
+            "page_count": 1,
+            "page_size": 500,
+            "total_count": 2
+        })
+    };
+
+    Mock::given(method("GET"))
+        .and(path("/api/queues/"))
+        .and(query_param("disable_stats", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page(vec![
+            queue("orders", "rabbit@node-1"),
+            queue("audit", "rabbit@node-1"),
+        ])))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/rebalance/queues"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/queues/"))
+        .and(query_param("disable_stats", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page(vec![
+            queue("orders", "rabbit@node-1"),
+            queue("audit", "rabbit@node-2"),
+        ])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let report = ctx
+        .rabbitmq
+        .rebalance_queues_with_report()
+        .await
+        .expect("failed to rebalance queues");
+
+    assert_eq!(report.before.len(), 1);
+    assert_eq!(report.before[0].node, "rabbit@node-1");
+    assert_eq!(report.before[0].total, 2);
+
+    assert_eq!(report.after.len(), 2);
+    assert_eq!(report.after[0].node, "rabbit@node-1");
+    assert_eq!(report.after[0].total, 1);
+    assert_eq!(report.after[1].node, "rabbit@node-2");
+    assert_eq!(report.after[1].total, 1);
+}
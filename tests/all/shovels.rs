@@ -0,0 +1,155 @@
+use crate::context::TestContext;
+use rabbitmq_management_client::api::queue::{QueueApi, RabbitMqQueueRequest};
+use rabbitmq_management_client::api::shovel::{
+    RabbitMqShovelAckMode, RabbitMqShovelDefinition, RabbitMqShovelDeleteAfter,
+    RabbitMqShovelDeleteAfterMode, RabbitMqShovelProtocol, ShovelApi,
+};
+use rabbitmq_management_client::errors::RabbitMqClientError;
+
+#[tokio::test]
+async fn can_crud_shovels() {
+    let ctx = TestContext::new();
+
+    let vhost = ctx
+        .create_random_vhost()
+        .await
+        .expect("failed to create vhost");
+
+    for queue in ["test-shovel-src", "test-shovel-dest"] {
+        ctx.rabbitmq
+            .create_queue(
+                vhost.name.clone(),
+                queue.to_string(),
+                RabbitMqQueueRequest {
+                    auto_delete: false,
+                    durable: true,
+                    arguments: None,
+                    node: None,
+                },
+            )
+            .await
+            .expect("failed to create queue");
+    }
+
+    ctx.rabbitmq
+        .create_shovel(
+            vhost.name.clone(),
+            "test-shovel".to_string(),
+            RabbitMqShovelDefinition {
+                src_protocol: Some(RabbitMqShovelProtocol::Amqp091),
+                src_uri: vec![format!("amqp:///{}", vhost.name)],
+                src_queue: Some("test-shovel-src".to_string()),
+                src_delete_after: Some(RabbitMqShovelDeleteAfter::Mode(
+                    RabbitMqShovelDeleteAfterMode::Never,
+                )),
+                dest_protocol: Some(RabbitMqShovelProtocol::Amqp091),
+                dest_uri: vec![format!("amqp:///{}", vhost.name)],
+                dest_queue: Some("test-shovel-dest".to_string()),
+                ack_mode: Some(RabbitMqShovelAckMode::OnConfirm),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect("failed to create shovel");
+
+    let shovel = ctx
+        .rabbitmq
+        .get_shovel(vhost.name.clone(), "test-shovel".to_string())
+        .await
+        .expect("failed to get shovel");
+
+    assert_eq!(shovel.value.src_queue, Some("test-shovel-src".to_string()));
+    assert_eq!(
+        shovel.value.dest_queue,
+        Some("test-shovel-dest".to_string())
+    );
+    assert_eq!(
+        shovel.value.ack_mode,
+        Some(RabbitMqShovelAckMode::OnConfirm)
+    );
+
+    let shovels = ctx
+        .rabbitmq
+        .list_shovels(Some(vhost.name.clone()))
+        .await
+        .expect("failed to list shovels");
+    assert_eq!(shovels.len(), 1);
+
+    let statuses = ctx
+        .rabbitmq
+        .list_shovel_status(Some(vhost.name.clone()))
+        .await
+        .expect("failed to list shovel status");
+    assert!(statuses.iter().any(|s| s.name == "test-shovel"));
+
+    ctx.rabbitmq
+        .delete_shovel(vhost.name.clone(), "test-shovel".to_string())
+        .await
+        .expect("failed to delete shovel");
+
+    let deleted = ctx
+        .rabbitmq
+        .get_shovel(vhost.name.clone(), "test-shovel".to_string())
+        .await;
+    assert!(matches!(deleted, Err(RabbitMqClientError::NotFound(_))));
+
+    ctx.delete_vhost(vhost.name)
+        .await
+        .expect("failed to delete vhost");
+}
+
+#[tokio::test]
+async fn validates_shovel_definition() {
+    let ctx = TestContext::new();
+
+    let both_sources = RabbitMqShovelDefinition {
+        src_uri: vec!["amqp://".to_string()],
+        src_queue: Some("source".to_string()),
+        src_exchange: Some("source".to_string()),
+        dest_uri: vec!["amqp://".to_string()],
+        dest_queue: Some("destination".to_string()),
+        ..Default::default()
+    };
+    assert!(matches!(
+        both_sources.validate(),
+        Err(RabbitMqClientError::InvalidRequest(_))
+    ));
+
+    // Validation happens before anything is sent to the broker
+    let result = ctx
+        .rabbitmq
+        .create_shovel(
+            "doesnotexist".to_string(),
+            "test-shovel".to_string(),
+            both_sources,
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(RabbitMqClientError::InvalidRequest(_))
+    ));
+
+    let amqp10_with_queue = RabbitMqShovelDefinition {
+        src_protocol: Some(RabbitMqShovelProtocol::Amqp10),
+        src_uri: vec!["amqp://".to_string()],
+        src_address: Some("source".to_string()),
+        dest_protocol: Some(RabbitMqShovelProtocol::Amqp10),
+        dest_uri: vec!["amqp://".to_string()],
+        dest_queue: Some("destination".to_string()),
+        ..Default::default()
+    };
+    assert!(matches!(
+        amqp10_with_queue.validate(),
+        Err(RabbitMqClientError::InvalidRequest(_))
+    ));
+
+    let valid = RabbitMqShovelDefinition {
+        src_uri: vec!["amqp://".to_string()],
+        src_exchange: Some("source".to_string()),
+        src_exchange_key: Some("#".to_string()),
+        dest_uri: vec!["amqp://".to_string()],
+        dest_exchange: Some("destination".to_string()),
+        ..Default::default()
+    };
+    assert!(valid.validate().is_ok());
+}
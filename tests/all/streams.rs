@@ -0,0 +1,119 @@
+use crate::context::TestContext;
+use rabbitmq_management_client::api::consumer::RabbitMqConsumerActivityStatus;
+use rabbitmq_management_client::api::stream::StreamApi;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn can_list_stream_connections() {
+    let ctx = TestContext::new();
+
+    let vhost = ctx
+        .create_random_vhost()
+        .await
+        .expect("failed to create vhost");
+
+    let connections = ctx
+        .rabbitmq
+        .list_stream_connections(Some(vhost.name.clone()))
+        .await
+        .expect("failed to list stream connections");
+    assert!(connections.is_empty());
+
+    let publishers = ctx
+        .rabbitmq
+        .list_stream_publishers(Some(vhost.name.clone()))
+        .await
+        .expect("failed to list stream publishers");
+    assert!(publishers.is_empty());
+
+    let consumers = ctx
+        .rabbitmq
+        .list_stream_consumers(Some(vhost.name.clone()))
+        .await
+        .expect("failed to list stream consumers");
+    assert!(consumers.is_empty());
+
+    ctx.delete_vhost(vhost.name)
+        .await
+        .expect("failed to delete vhost");
+}
+
+#[tokio::test]
+async fn can_list_stream_connection_publishers_and_consumers() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    let connection_details = serde_json::json!({
+        "name": "172.18.0.1:52112 -> 172.18.0.2:5552",
+        "node": "rabbit@rabbitmq",
+        "user": "guest",
+        "peer_host": "172.18.0.1",
+        "peer_port": 52112
+    });
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/api/stream/connections/orders/my-connection/publishers",
+        ))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                "publisher_id": 1,
+                "reference": "invoice-writer",
+                "queue": { "name": "invoices", "vhost": "orders" },
+                "connection_details": connection_details,
+                "published": 1200,
+                "confirmed": 1195,
+                "errored": 5
+            }])),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(
+            "/api/stream/connections/orders/my-connection/consumers",
+        ))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                "subscription_id": 0,
+                "queue": { "name": "invoices", "vhost": "orders" },
+                "connection_details": connection_details,
+                "offset": 4000,
+                "offset_lag": 250,
+                "credits": 10,
+                "consumed": 4000,
+                "active": true,
+                "activity_status": "single_active",
+                "properties": { "name": "invoice-reader" }
+            }])),
+        )
+        .mount(&server)
+        .await;
+
+    let publishers = ctx
+        .rabbitmq
+        .list_stream_connection_publishers("orders".to_string(), "my-connection".to_string())
+        .await
+        .expect("failed to list stream connection publishers");
+
+    assert_eq!(publishers.len(), 1);
+    assert_eq!(publishers[0].reference, "invoice-writer");
+    assert_eq!(publishers[0].queue.name, "invoices");
+    assert_eq!(publishers[0].confirmed, 1195);
+    assert_eq!(publishers[0].errored, 5);
+
+    let consumers = ctx
+        .rabbitmq
+        .list_stream_connection_consumers("orders".to_string(), "my-connection".to_string())
+        .await
+        .expect("failed to list stream connection consumers");
+
+    assert_eq!(consumers.len(), 1);
+    assert_eq!(consumers[0].offset, 4000);
+    assert_eq!(consumers[0].offset_lag, 250);
+    assert_eq!(consumers[0].credits, 10);
+    assert_eq!(
+        consumers[0].activity_status,
+        Some(RabbitMqConsumerActivityStatus::SingleActive)
+    );
+}
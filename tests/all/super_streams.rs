@@ -0,0 +1,300 @@
+use crate::context::TestContext;
+use rabbitmq_management_client::api::queue::{QueueApi, RabbitMqArgument};
+use rabbitmq_management_client::api::super_stream::{
+    RabbitMqSuperStreamPartitions, RabbitMqSuperStreamRequest, SuperStreamApi,
+};
+use rabbitmq_management_client::errors::RabbitMqClientError;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn can_crud_super_streams() {
+    let ctx = TestContext::new();
+
+    let vhost = ctx
+        .create_random_vhost()
+        .await
+        .expect("failed to create vhost");
+
+    let super_stream = ctx
+        .rabbitmq
+        .create_super_stream(
+            vhost.name.clone(),
+            "invoices".to_string(),
+            RabbitMqSuperStreamRequest {
+                partitions: RabbitMqSuperStreamPartitions::Count(3),
+                arguments: HashMap::from([
+                    (
+                        "x-max-age".to_string(),
+                        RabbitMqArgument::String("7D".to_string()),
+                    ),
+                    (
+                        "x-max-length-bytes".to_string(),
+                        RabbitMqArgument::Decimal(Decimal::from(1_000_000_000)),
+                    ),
+                ]),
+            },
+        )
+        .await
+        .expect("failed to create super stream");
+
+    let streams: Vec<String> = super_stream
+        .partitions
+        .iter()
+        .map(|p| p.stream.clone())
+        .collect();
+    assert_eq!(streams, vec!["invoices-0", "invoices-1", "invoices-2"]);
+    assert_eq!(super_stream.partitions[2].binding_key, "2");
+    assert_eq!(super_stream.partitions[2].order, Some(2));
+
+    let queue = ctx
+        .rabbitmq
+        .get_queue(vhost.name.clone(), "invoices-1".to_string())
+        .await
+        .expect("failed to get partition");
+    assert_eq!(queue.kind, "stream");
+    assert_eq!(
+        queue.arguments.get("x-max-length-bytes"),
+        Some(&RabbitMqArgument::Decimal(Decimal::from(1_000_000_000)))
+    );
+
+    ctx.rabbitmq
+        .delete_super_stream(vhost.name.clone(), "invoices".to_string())
+        .await
+        .expect("failed to delete super stream");
+
+    let deleted = ctx
+        .rabbitmq
+        .get_super_stream(vhost.name.clone(), "invoices".to_string())
+        .await;
+    assert!(matches!(deleted, Err(RabbitMqClientError::NotFound(_))));
+
+    let deleted_partition = ctx
+        .rabbitmq
+        .get_queue(vhost.name.clone(), "invoices-1".to_string())
+        .await;
+    assert!(matches!(
+        deleted_partition,
+        Err(RabbitMqClientError::NotFound(_))
+    ));
+
+    ctx.delete_vhost(vhost.name)
+        .await
+        .expect("failed to delete vhost");
+}
+
+#[tokio::test]
+async fn reads_super_stream_partitions_in_order() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/api/exchanges/orders/invoices"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "invoices",
+            "vhost": "orders",
+            "type": "direct",
+            "durable": true,
+            "auto_delete": false,
+            "internal": false,
+            "user_who_performed_action": "guest",
+            "arguments": { "x-super-stream": true }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/exchanges/orders/invoices/bindings/source"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {
+                "source": "invoices",
+                "vhost": "orders",
+                "destination": "invoices-apac",
+                "destination_type": "queue",
+                "routing_key": "apac",
+                "properties_key": "apac",
+                "arguments": {}
+            },
+            {
+                "source": "invoices",
+                "vhost": "orders",
+                "destination": "invoices-eu",
+                "destination_type": "queue",
+                "routing_key": "eu",
+                "properties_key": "eu",
+                "arguments": { "x-stream-partition-order": 1 }
+            },
+            {
+                "source": "invoices",
+                "vhost": "orders",
+                "destination": "invoices-us",
+                "destination_type": "queue",
+                "routing_key": "us",
+                "properties_key": "us",
+                "arguments": { "x-stream-partition-order": 0 }
+            }
+        ])))
+        .mount(&server)
+        .await;
+
+    let super_stream = ctx
+        .rabbitmq
+        .get_super_stream("orders".to_string(), "invoices".to_string())
+        .await
+        .expect("failed to get super stream");
+
+    assert_eq!(super_stream.partitions.len(), 3);
+    assert_eq!(super_stream.partitions[0].stream, "invoices-us");
+    assert_eq!(super_stream.partitions[0].order, Some(0));
+    assert_eq!(super_stream.partitions[1].stream, "invoices-eu");
+    assert_eq!(super_stream.partitions[1].binding_key, "eu");
+    assert_eq!(super_stream.partitions[2].stream, "invoices-apac");
+    assert_eq!(super_stream.partitions[2].order, None);
+}
+
+#[tokio::test]
+async fn rejects_plain_exchanges_as_super_streams() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/api/exchanges/orders/invoices"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "invoices",
+            "vhost": "orders",
+            "type": "direct",
+            "durable": true,
+            "auto_delete": false,
+            "internal": false,
+            "user_who_performed_action": "guest",
+            "arguments": {}
+        })))
+        .mount(&server)
+        .await;
+
+    let result = ctx
+        .rabbitmq
+        .get_super_stream("orders".to_string(), "invoices".to_string())
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(RabbitMqClientError::InvalidRequest(_))
+    ));
+}
+
+#[tokio::test]
+async fn rolls_back_partially_created_super_streams() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/api/exchanges/orders"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "filtered_count": 0,
+            "item_count": 0,
+            "items": [],
+            "page": 1,
+            "page_count": 1,
+            "page_size": 100,
+            "total_count": 0
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/api/exchanges/orders/invoices"))
+        .and(body_json(serde_json::json!({
+            "type": "direct",
+            "auto_delete": false,
+            "durable": true,
+            "internal": false,
+            "arguments": { "x-super-stream": true }
+        })))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    for partition in ["invoices-0", "invoices-1"] {
+        Mock::given(method("GET"))
+            .and(path(format!("/api/queues/orders/{}", partition)))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+    }
+
+    Mock::given(method("PUT"))
+        .and(path("/api/queues/orders/invoices-0"))
+        .and(body_json(serde_json::json!({
+            "auto_delete": false,
+            "durable": true,
+            "arguments": {
+                "x-queue-type": "stream",
+                "x-max-length-bytes": 1_000_000_000
+            }
+        })))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/bindings/orders/e/invoices/q/invoices-0"))
+        .and(body_json(serde_json::json!({
+            "routing_key": "0",
+            "arguments": { "x-stream-partition-order": 0 }
+        })))
+        .respond_with(ResponseTemplate::new(201).insert_header("Location", "invoices-0/0/~"))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/api/queues/orders/invoices-1"))
+        .respond_with(ResponseTemplate::new(500).set_body_json(serde_json::json!({
+            "error": "internal_error",
+            "reason": "timeout"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/queues/orders/invoices-0"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/api/queues/orders/invoices-1"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(0)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/api/exchanges/orders/invoices"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let result = ctx
+        .rabbitmq
+        .create_super_stream(
+            "orders".to_string(),
+            "invoices".to_string(),
+            RabbitMqSuperStreamRequest {
+                partitions: RabbitMqSuperStreamPartitions::Count(3),
+                arguments: HashMap::from([(
+                    "x-max-length-bytes".to_string(),
+                    RabbitMqArgument::Decimal(Decimal::from(1_000_000_000)),
+                )]),
+            },
+        )
+        .await;
+
+    assert!(result.is_err());
+    assert!(!matches!(
+        result,
+        Err(RabbitMqClientError::AlreadyExists(_))
+    ));
+}
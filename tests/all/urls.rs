@@ -0,0 +1,148 @@
+use crate::context::TestContext;
+use rabbitmq_management_client::api::binding::{BindingApi, RabbitMqBindingDestinationType};
+use rabbitmq_management_client::api::exchange::ExchangeApi;
+use rabbitmq_management_client::api::queue::{QueueApi, RabbitMqQueueRequest};
+use rabbitmq_management_client::api::user::UserApi;
+use rabbitmq_management_client::api::vhost::VhostApi;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mock_empty(server: &MockServer, http_method: &str, expected_path: &str) {
+    Mock::given(method(http_method))
+        .and(path(expected_path))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn encodes_default_vhost() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    mock_empty(&server, "DELETE", "/api/queues/%2F/orders").await;
+    mock_empty(&server, "DELETE", "/api/vhosts/%2F").await;
+
+    ctx.rabbitmq
+        .delete_queue("/".to_string(), "orders".to_string())
+        .await
+        .expect("failed to delete queue");
+    ctx.rabbitmq
+        .delete_vhost("/".to_string())
+        .await
+        .expect("failed to delete vhost");
+}
+
+#[tokio::test]
+async fn encodes_slashes_in_names() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    mock_empty(
+        &server,
+        "DELETE",
+        "/api/exchanges/tenant%2Fa/events%2Forders",
+    )
+    .await;
+    mock_empty(&server, "DELETE", "/api/users/team%2Fops").await;
+
+    ctx.rabbitmq
+        .delete_exchange("tenant/a".to_string(), "events/orders".to_string())
+        .await
+        .expect("failed to delete exchange");
+    ctx.rabbitmq
+        .delete_user("team/ops".to_string())
+        .await
+        .expect("failed to delete user");
+}
+
+#[tokio::test]
+async fn encodes_spaces_and_reserved_characters() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    mock_empty(&server, "PUT", "/api/queues/my%20vhost/my%20queue").await;
+    mock_empty(
+        &server,
+        "DELETE",
+        "/api/queues/my%20vhost/a%23b%3Fc%25d%2Be",
+    )
+    .await;
+
+    ctx.rabbitmq
+        .update_queue(
+            "my vhost".to_string(),
+            "my queue".to_string(),
+            RabbitMqQueueRequest {
+                auto_delete: false,
+                durable: true,
+                arguments: None,
+                node: None,
+            },
+        )
+        .await
+        .expect("failed to update queue");
+    ctx.rabbitmq
+        .delete_queue("my vhost".to_string(), "a#b?c%d+e".to_string())
+        .await
+        .expect("failed to delete queue");
+}
+
+#[tokio::test]
+async fn encodes_unicode() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    mock_empty(
+        &server,
+        "DELETE",
+        "/api/queues/caf%C3%A9/%E6%B3%A8%E6%96%87-%C3%BC",
+    )
+    .await;
+
+    ctx.rabbitmq
+        .delete_queue("café".to_string(), "注文-ü".to_string())
+        .await
+        .expect("failed to delete queue");
+}
+
+#[tokio::test]
+async fn preserves_binding_properties_keys() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    mock_empty(
+        &server,
+        "DELETE",
+        "/api/bindings/%2F/e/amq.topic/q/orders/orders.eu~aBcD12",
+    )
+    .await;
+    mock_empty(
+        &server,
+        "DELETE",
+        "/api/bindings/%2F/e/amq.topic/q/orders/a%252Fb~aBcD12",
+    )
+    .await;
+
+    ctx.rabbitmq
+        .delete_binding(
+            "/".to_string(),
+            "amq.topic".to_string(),
+            "orders".to_string(),
+            RabbitMqBindingDestinationType::Queue,
+            "orders.eu~aBcD12".to_string(),
+        )
+        .await
+        .expect("failed to delete binding");
+    ctx.rabbitmq
+        .delete_binding(
+            "/".to_string(),
+            "amq.topic".to_string(),
+            "orders".to_string(),
+            RabbitMqBindingDestinationType::Queue,
+            "a%2Fb~aBcD12".to_string(),
+        )
+        .await
+        .expect("failed to delete binding");
+}
@@ -0,0 +1,80 @@
+use crate::context::TestContext;
+use rabbitmq_management_client::api::user::{RabbitMqUserCreateRequest, UserApi};
+use rabbitmq_management_client::api::user_limit::{
+    RabbitMqUserLimit, RabbitMqUserLimits, UserLimitApi,
+};
+use uuid::Uuid;
+
+#[tokio::test]
+async fn can_crud_user_limits() {
+    let ctx = TestContext::new();
+
+    let user = Uuid::new_v4().to_string();
+    ctx.rabbitmq
+        .create_user(RabbitMqUserCreateRequest {
+            name: user.clone(),
+            password: Some("fake_password".to_string()),
+            password_hash: None,
+            hashing_algorithm: None,
+            tags: vec![],
+        })
+        .await
+        .expect("failed to create user");
+
+    let limits = ctx
+        .rabbitmq
+        .get_user_limits(user.clone())
+        .await
+        .expect("failed to get user limits");
+
+    assert_eq!(limits, RabbitMqUserLimits::default());
+
+    ctx.rabbitmq
+        .set_user_limits(
+            user.clone(),
+            RabbitMqUserLimits {
+                max_connections: Some(10),
+                max_channels: Some(50),
+            },
+        )
+        .await
+        .expect("failed to set user limits");
+
+    let limits = ctx
+        .rabbitmq
+        .get_user_limits(user.clone())
+        .await
+        .expect("failed to get user limits");
+
+    assert_eq!(limits.max_connections, Some(10));
+    assert_eq!(limits.max_channels, Some(50));
+
+    let all_limits = ctx
+        .rabbitmq
+        .list_user_limits()
+        .await
+        .expect("failed to list user limits");
+
+    assert!(all_limits
+        .iter()
+        .any(|entry| entry.user == user && entry.value == limits));
+
+    ctx.rabbitmq
+        .delete_user_limit(user.clone(), RabbitMqUserLimit::MaxChannels)
+        .await
+        .expect("failed to delete max-channels");
+
+    let limits = ctx
+        .rabbitmq
+        .get_user_limits(user.clone())
+        .await
+        .expect("failed to get user limits");
+
+    assert_eq!(limits.max_connections, Some(10));
+    assert_eq!(limits.max_channels, None);
+
+    ctx.rabbitmq
+        .delete_user(user)
+        .await
+        .expect("failed to delete user");
+}
@@ -1,9 +1,13 @@
 use crate::context::TestContext;
+use rabbitmq_management_client::api::permission::PermissionApi;
 use rabbitmq_management_client::api::user::{
-    RabbitMqUserCreateRequest, RabbitMqUserTag, RabbitMqUsersBulkDeleteRequest, UserApi,
+    RabbitMqHashingAlgorithm, RabbitMqUserCreateRequest, RabbitMqUserTag,
+    RabbitMqUserUpdateRequest, RabbitMqUsersBulkDeleteRequest, UserApi,
 };
 use rabbitmq_management_client::errors::RabbitMqClientError;
 use uuid::Uuid;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[tokio::test]
 async fn can_list_users() {
@@ -206,3 +210,330 @@ async fn can_bulk_delete_users() {
 
     assert_eq!(original_users.len(), after_delete_users.len());
 }
+
+#[tokio::test]
+async fn lists_user_permissions_without_deleting_them() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/api/users/bob/permissions"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                "user": "bob",
+                "vhost": "/",
+                "configure": ".*",
+                "write": ".*",
+                "read": ".*"
+            }])),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let permissions = ctx
+        .rabbitmq
+        .list_user_permissions("bob".to_string())
+        .await
+        .expect("failed to list user permissions");
+
+    assert_eq!(permissions.len(), 1);
+    assert_eq!(permissions[0].vhost, "/");
+    assert_eq!(permissions[0].configure, ".*");
+}
+
+#[tokio::test]
+async fn lists_user_topic_permissions_without_deleting_them() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/api/users/bob/topic-permissions"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                "user": "bob",
+                "vhost": "/",
+                "exchange": "amq.topic",
+                "write": "^orders\\.",
+                "read": ".*"
+            }])),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let permissions = ctx
+        .rabbitmq
+        .list_user_topic_permissions("bob".to_string())
+        .await
+        .expect("failed to list user topic permissions");
+
+    assert_eq!(permissions.len(), 1);
+    assert_eq!(permissions[0].exchange, "amq.topic");
+    assert_eq!(permissions[0].write, "^orders\\.");
+}
+
+#[tokio::test]
+async fn can_update_user_tags_and_password() {
+    let ctx = TestContext::new();
+
+    let name = Uuid::new_v4().to_string();
+    ctx.rabbitmq
+        .create_user(RabbitMqUserCreateRequest {
+            name: name.clone(),
+            password: Some("fake_password".to_string()),
+            password_hash: None,
+            hashing_algorithm: None,
+            tags: vec![RabbitMqUserTag::Management],
+        })
+        .await
+        .expect("failed to create user");
+
+    let original = ctx
+        .rabbitmq
+        .get_user(name.clone())
+        .await
+        .expect("failed to get user");
+
+    ctx.rabbitmq
+        .update_user(RabbitMqUserUpdateRequest {
+            name: name.clone(),
+            password: None,
+            password_hash: None,
+            hashing_algorithm: None,
+            tags: Some(vec![
+                RabbitMqUserTag::Policymaker,
+                RabbitMqUserTag::Other("custom".to_string()),
+            ]),
+        })
+        .await
+        .expect("failed to update user tags");
+
+    let updated = ctx
+        .rabbitmq
+        .get_user(name.clone())
+        .await
+        .expect("failed to get user");
+
+    assert_eq!(updated.password_hash, original.password_hash);
+    assert!(updated.tags.contains(&RabbitMqUserTag::Policymaker));
+    assert!(updated
+        .tags
+        .contains(&RabbitMqUserTag::Other("custom".to_string())));
+
+    ctx.rabbitmq
+        .change_password(name.clone(), "another_fake_password".to_string())
+        .await
+        .expect("failed to change password");
+
+    let changed = ctx
+        .rabbitmq
+        .get_user(name.clone())
+        .await
+        .expect("failed to get user");
+
+    assert_ne!(changed.password_hash, original.password_hash);
+    assert_eq!(changed.tags, updated.tags);
+
+    ctx.rabbitmq
+        .delete_user(name)
+        .await
+        .expect("failed to delete user");
+}
+
+#[tokio::test]
+async fn can_clear_user_permissions() {
+    let ctx = TestContext::new();
+
+    let vhost = ctx
+        .create_random_vhost()
+        .await
+        .expect("failed to create vhost");
+
+    let name = Uuid::new_v4().to_string();
+    ctx.rabbitmq
+        .create_user(RabbitMqUserCreateRequest {
+            name: name.clone(),
+            password: Some("fake_password".to_string()),
+            password_hash: None,
+            hashing_algorithm: None,
+            tags: vec![],
+        })
+        .await
+        .expect("failed to create user");
+
+    for target in ["/".to_string(), vhost.name.clone()] {
+        ctx.rabbitmq
+            .set_permission(
+                target.clone(),
+                name.clone(),
+                ".*".to_string(),
+                ".*".to_string(),
+                ".*".to_string(),
+            )
+            .await
+            .expect("failed to set permission");
+        ctx.rabbitmq
+            .set_topic_permission(
+                target,
+                name.clone(),
+                "amq.topic".to_string(),
+                ".*".to_string(),
+                ".*".to_string(),
+            )
+            .await
+            .expect("failed to set topic permission");
+    }
+
+    let permissions = ctx
+        .rabbitmq
+        .list_user_permissions(name.clone())
+        .await
+        .expect("failed to list user permissions");
+    assert_eq!(permissions.len(), 2);
+
+    let topic_permissions = ctx
+        .rabbitmq
+        .list_user_topic_permissions(name.clone())
+        .await
+        .expect("failed to list user topic permissions");
+    assert_eq!(topic_permissions.len(), 2);
+
+    ctx.rabbitmq
+        .clear_user_permissions(name.clone())
+        .await
+        .expect("failed to clear user permissions");
+
+    let permissions = ctx
+        .rabbitmq
+        .list_user_permissions(name.clone())
+        .await
+        .expect("failed to list user permissions");
+    assert!(permissions.is_empty());
+
+    let topic_permissions = ctx
+        .rabbitmq
+        .list_user_topic_permissions(name.clone())
+        .await
+        .expect("failed to list user topic permissions");
+    assert!(topic_permissions.is_empty());
+
+    ctx.rabbitmq
+        .delete_user(name)
+        .await
+        .expect("failed to delete user");
+
+    ctx.delete_vhost(vhost.name)
+        .await
+        .expect("failed to delete vhost");
+}
+
+#[tokio::test]
+async fn deserializes_custom_user_tags() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/api/users"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                "name": "ops",
+                "password_hash": "",
+                "hashing_algorithm": "rabbit_password_hashing_sha256",
+                "tags": ["policymaker", "impersonator", "on-call"]
+            }])),
+        )
+        .mount(&server)
+        .await;
+
+    let users = ctx
+        .rabbitmq
+        .list_users()
+        .await
+        .expect("failed to list users");
+
+    assert_eq!(
+        users[0].tags,
+        vec![
+            RabbitMqUserTag::Policymaker,
+            RabbitMqUserTag::Impersonator,
+            RabbitMqUserTag::Other("on-call".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn update_user_keeps_existing_password_hash() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/api/users/ops"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "ops",
+            "password_hash": "kI3GCqW5JLMJa4iX1lo7X4D6XbYqlLgxIs30+P6tENUV2POR",
+            "hashing_algorithm": "rabbit_password_hashing_sha256",
+            "tags": ["management"]
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/api/users/ops"))
+        .and(body_json(serde_json::json!({
+            "password_hash": "kI3GCqW5JLMJa4iX1lo7X4D6XbYqlLgxIs30+P6tENUV2POR",
+            "hashing_algorithm": "rabbit_password_hashing_sha256",
+            "tags": ["monitoring"]
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    ctx.rabbitmq
+        .update_user(RabbitMqUserUpdateRequest {
+            name: "ops".to_string(),
+            password: None,
+            password_hash: None,
+            hashing_algorithm: None,
+            tags: Some(vec![RabbitMqUserTag::Monitoring]),
+        })
+        .await
+        .expect("failed to update user");
+}
+
+#[tokio::test]
+async fn update_user_rejects_password_with_password_hash() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    let result = ctx
+        .rabbitmq
+        .update_user(RabbitMqUserUpdateRequest {
+            name: "ops".to_string(),
+            password: Some("fake_password".to_string()),
+            password_hash: Some("kI3GCqW5JLMJa4iX1lo7X4D6XbYqlLgxIs30+P6tENUV2POR".to_string()),
+            hashing_algorithm: Some(RabbitMqHashingAlgorithm::RabbitPasswordHashingSha256),
+            tags: None,
+        })
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(RabbitMqClientError::InvalidRequest(_))
+    ));
+    assert!(server
+        .received_requests()
+        .await
+        .expect("request recording is disabled")
+        .is_empty());
+}
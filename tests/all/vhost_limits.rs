@@ -0,0 +1,232 @@
+use crate::context::TestContext;
+use rabbitmq_management_client::api::vhost::{RabbitMqVhostRequest, VhostApi};
+use rabbitmq_management_client::api::vhost_limit::{
+    RabbitMqVhostLimit, RabbitMqVhostLimits, VhostLimitApi,
+};
+use rabbitmq_management_client::errors::RabbitMqClientError;
+use reqwest::StatusCode;
+use uuid::Uuid;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn can_crud_vhost_limits() {
+    let ctx = TestContext::new();
+
+    let vhost = ctx
+        .create_random_vhost()
+        .await
+        .expect("failed to create vhost");
+
+    assert_eq!(vhost.limits, Some(RabbitMqVhostLimits::default()));
+
+    ctx.rabbitmq
+        .set_vhost_limit(vhost.name.clone(), RabbitMqVhostLimit::MaxConnections, 10)
+        .await
+        .expect("failed to set max-connections");
+
+    ctx.rabbitmq
+        .set_vhost_limit(vhost.name.clone(), RabbitMqVhostLimit::MaxQueues, 5)
+        .await
+        .expect("failed to set max-queues");
+
+    let limits = ctx
+        .rabbitmq
+        .get_vhost_limits(vhost.name.clone())
+        .await
+        .expect("failed to get vhost limits");
+
+    assert_eq!(limits.max_connections, Some(10));
+    assert_eq!(limits.max_queues, Some(5));
+
+    let all_limits = ctx
+        .rabbitmq
+        .list_vhost_limits()
+        .await
+        .expect("failed to list vhost limits");
+
+    assert!(all_limits
+        .iter()
+        .any(|entry| entry.vhost == vhost.name && entry.value == limits));
+
+    ctx.rabbitmq
+        .delete_vhost_limit(vhost.name.clone(), RabbitMqVhostLimit::MaxConnections)
+        .await
+        .expect("failed to delete max-connections");
+
+    let limits = ctx
+        .rabbitmq
+        .get_vhost(vhost.name.clone())
+        .await
+        .expect("failed to get vhost")
+        .limits
+        .expect("missing vhost limits");
+
+    assert_eq!(limits.max_connections, None);
+    assert_eq!(limits.max_queues, Some(5));
+
+    ctx.delete_vhost(vhost.name)
+        .await
+        .expect("failed to delete vhost");
+}
+
+#[tokio::test]
+async fn can_create_vhost_with_limits() {
+    let ctx = TestContext::new();
+
+    let name = Uuid::new_v4().to_string();
+
+    ctx.rabbitmq
+        .create_vhost(RabbitMqVhostRequest {
+            name: name.clone(),
+            description: None,
+            tags: vec![],
+            tracing: false,
+            default_queue_type: None,
+            limits: Some(RabbitMqVhostLimits {
+                max_connections: Some(20),
+                max_queues: None,
+            }),
+        })
+        .await
+        .expect("failed to create vhost");
+
+    let vhost = ctx
+        .rabbitmq
+        .list_vhosts()
+        .await
+        .expect("failed to list vhosts")
+        .into_iter()
+        .find(|vh| vh.name == name)
+        .expect("could not find the newly created vhost");
+
+    assert_eq!(
+        vhost.limits,
+        Some(RabbitMqVhostLimits {
+            max_connections: Some(20),
+            max_queues: None,
+        })
+    );
+
+    ctx.delete_vhost(name)
+        .await
+        .expect("failed to delete vhost");
+}
+
+#[tokio::test]
+async fn reads_vhosts_when_limits_are_forbidden() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    let vhost = serde_json::json!({
+        "cluster_state": { "rabbit@rabbitmq": "running" },
+        "default_queue_type": "classic",
+        "description": "",
+        "metadata": { "description": "", "tags": [] },
+        "name": "tenant-a",
+        "tags": [],
+        "tracing": false
+    });
+
+    Mock::given(method("GET"))
+        .and(path("/api/vhosts"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([vhost])))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/vhosts/tenant-a"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(vhost.clone()))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/vhost-limits"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+            "error": "not_authorised",
+            "reason": "Not administrator user"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/vhost-limits/tenant-a"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+            "error": "not_authorised",
+            "reason": "Not administrator user"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let vhosts = ctx
+        .rabbitmq
+        .list_vhosts()
+        .await
+        .expect("failed to list vhosts");
+    assert_eq!(vhosts.len(), 1);
+    assert_eq!(vhosts[0].limits, None);
+
+    let vhost = ctx
+        .rabbitmq
+        .get_vhost("tenant-a".to_string())
+        .await
+        .expect("failed to get vhost");
+    assert_eq!(vhost.name, "tenant-a");
+    assert_eq!(vhost.limits, None);
+}
+
+#[tokio::test]
+async fn returns_vhost_limits_errors() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    let vhost = serde_json::json!({
+        "cluster_state": { "rabbit@rabbitmq": "running" },
+        "default_queue_type": "classic",
+        "description": "",
+        "metadata": { "description": "", "tags": [] },
+        "name": "tenant-a",
+        "tags": [],
+        "tracing": false
+    });
+
+    Mock::given(method("GET"))
+        .and(path("/api/vhosts"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([vhost])))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/vhosts/tenant-a"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(vhost.clone()))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/vhost-limits"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/vhost-limits/tenant-a"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let result = ctx.rabbitmq.list_vhosts().await;
+    assert!(matches!(
+        result,
+        Err(RabbitMqClientError::ApiError(ref e)) if e.code == StatusCode::INTERNAL_SERVER_ERROR
+    ));
+
+    let result = ctx.rabbitmq.get_vhost("tenant-a".to_string()).await;
+    assert!(matches!(
+        result,
+        Err(RabbitMqClientError::ApiError(ref e)) if e.code == StatusCode::INTERNAL_SERVER_ERROR
+    ));
+}
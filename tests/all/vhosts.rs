@@ -1,6 +1,9 @@
 use crate::context::TestContext;
 use rabbitmq_management_client::api::vhost::{RabbitMqVhostRequest, VhostApi};
 use rabbitmq_management_client::errors::RabbitMqClientError;
+use uuid::Uuid;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[tokio::test]
 async fn can_list_vhosts() {
@@ -30,6 +33,8 @@ async fn can_crud_vhost() {
             description: Some("testing vhost".to_string()),
             tags: vec!["test1".to_string(), "test2".to_string()],
             tracing: true,
+            default_queue_type: None,
+            limits: None,
         })
         .await
         .expect("failed to create vhost");
@@ -75,6 +80,8 @@ async fn cannot_create_if_vhosts_exists() {
             description: None,
             tags: vec![],
             tracing: false,
+            default_queue_type: None,
+            limits: None,
         })
         .await;
 
@@ -89,3 +96,98 @@ async fn returns_not_found() {
 
     assert!(matches!(result, Err(RabbitMqClientError::NotFound(_))));
 }
+
+#[tokio::test]
+async fn can_round_trip_vhost_description_tags_and_default_queue_type() {
+    let ctx = TestContext::new();
+
+    let name = Uuid::new_v4().to_string();
+    ctx.rabbitmq
+        .create_vhost(RabbitMqVhostRequest {
+            name: name.clone(),
+            description: Some("tenant vhost".to_string()),
+            tags: vec!["tenant".to_string(), "eu".to_string()],
+            tracing: false,
+            default_queue_type: Some("quorum".to_string()),
+            limits: None,
+        })
+        .await
+        .expect("failed to create vhost");
+
+    let vhost = ctx
+        .rabbitmq
+        .get_vhost(name.clone())
+        .await
+        .expect("failed to get vhost");
+
+    assert_eq!(vhost.default_queue_type, "quorum");
+    assert_eq!(vhost.description, "tenant vhost");
+    assert_eq!(vhost.tags, vec!["tenant".to_string(), "eu".to_string()]);
+    assert!(!vhost.tracing);
+    assert_eq!(
+        vhost.metadata.default_queue_type,
+        Some("quorum".to_string())
+    );
+    assert_eq!(vhost.metadata.description, "tenant vhost");
+    assert_eq!(
+        vhost.metadata.tags,
+        vec!["tenant".to_string(), "eu".to_string()]
+    );
+
+    ctx.rabbitmq
+        .update_vhost(RabbitMqVhostRequest {
+            name: name.clone(),
+            description: Some("archived tenant vhost".to_string()),
+            tags: vec!["archived".to_string()],
+            tracing: true,
+            default_queue_type: Some("quorum".to_string()),
+            limits: None,
+        })
+        .await
+        .expect("failed to update vhost");
+
+    let vhost = ctx
+        .rabbitmq
+        .get_vhost(name.clone())
+        .await
+        .expect("failed to get vhost");
+
+    assert_eq!(vhost.description, "archived tenant vhost");
+    assert_eq!(vhost.tags, vec!["archived".to_string()]);
+    assert!(vhost.tracing);
+    assert_eq!(vhost.metadata.description, "archived tenant vhost");
+    assert_eq!(vhost.metadata.tags, vec!["archived".to_string()]);
+
+    ctx.delete_vhost(name)
+        .await
+        .expect("failed to delete vhost");
+}
+
+#[tokio::test]
+async fn can_protect_vhost_from_deletion() {
+    let server = MockServer::start().await;
+    let ctx = TestContext::new_with_api_url(server.uri());
+
+    Mock::given(method("POST"))
+        .and(path("/api/vhosts/tenant-a/deletion/protection"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/api/vhosts/tenant-a/deletion/protection"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    ctx.rabbitmq
+        .protect_vhost_from_deletion("tenant-a".to_string())
+        .await
+        .expect("failed to protect vhost from deletion");
+
+    ctx.rabbitmq
+        .unprotect_vhost_from_deletion("tenant-a".to_string())
+        .await
+        .expect("failed to unprotect vhost from deletion");
+}